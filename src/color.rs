@@ -0,0 +1,61 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Optional ANSI coloring of failure messages.
+//!
+//! Colors are only used if the `color` feature is enabled and the failure is reported to a terminal.
+
+use super::MatchResult;
+
+const BOLD: &str = "\x1b[1m";
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+#[cfg(feature = "color")]
+fn enabled() -> bool {
+    use std::io::IsTerminal;
+    std::env::var_os("NO_COLOR").is_none() && std::io::stderr().is_terminal()
+}
+
+#[cfg(not(feature = "color"))]
+fn enabled() -> bool { false }
+
+fn paint(color: &str, text: &str) -> String {
+    format!("{}{}{}", color, text, RESET)
+}
+
+/// Formats the failure of a matcher, highlighting the matcher's name and the expected and actual values if enabled.
+pub fn failure(name: &str, reason: &str) -> String {
+    if !enabled() {
+        return MatchResult::Failed { name: name.to_owned(), reason: reason.to_owned() }.to_string();
+    }
+
+    let reason = reason.lines().map(|line| {
+        if line.starts_with("  Expected:") || line.starts_with("  Bound:") {
+            paint(GREEN, line)
+        } else if line.starts_with("  Got:") {
+            paint(RED, line)
+        } else {
+            line.to_owned()
+        }
+    }).collect::<Vec<_>>().join("\n");
+    format!("Failed assertion of matcher: {}\n{}", paint(BOLD, name), reason)
+}
+
+/// Removes the ANSI color codes inserted by `failure()`.
+pub fn strip(message: &str) -> String {
+    [BOLD, GREEN, RED, RESET].iter().fold(message.to_owned(), |message, code| message.replace(code, ""))
+}
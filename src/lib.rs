@@ -15,7 +15,7 @@
 
 //! Galvanic-assert: Matcher-based assertions for easier testing
 //! ============================================================
-//! This crate provides a new assertion macros (`assert_that!`, `expect_that!`, `get_expectation_for!`) based on **matching predicates** (matchers) to
+//! This crate provides a new assertion macros (`assert_that!`, `try_assert_that!`, `assert_all!`, `assert_panics_for!`, `expect!`, `eventually!`, `for_samples!`, `expect_that!`, `get_expectation_for!`) based on **matching predicates** (matchers) to
 //!
 //!  * make **writing** asserts easier
 //!  * make **reading** asserts comprehendable
@@ -25,6 +25,19 @@
 //!  * be used with your favourite test framework
 //!
 //! The crate will be part of **galvanic**---a complete test framework for **Rust**.
+//!
+//! Enable the `color` feature to highlight the matcher name as well as the expected and actual values of failed assertions.
+//! Colors are only used if the failure is reported to a terminal and the `NO_COLOR` environment variable is not set.
+//!
+//! Enable the `json` feature for matchers inspecting `serde_json::Value`s in the `matchers::json` module.
+
+// lifetime bounds are kept next to the generic parameters while trait bounds go into the where clause
+#![allow(clippy::multiple_bound_locations)]
+
+#[cfg(feature = "regex")]
+extern crate regex;
+#[cfg(feature = "json")]
+extern crate serde_json;
 
 use std::fmt::{Debug, Display, Formatter, Result as FormatResult};
 
@@ -49,26 +62,136 @@ use std::fmt::{Debug, Display, Formatter, Result as FormatResult};
 ///         if x == 1 { builder.matched } else { builder.failed_because("some reason") }
 ///     })
 ///     ```
+///
+///     One-off predicates are written most easily with the `satisfies` matcher.
+///
+///     ```rust,ignore
+///     assert_that!(&1, satisfies("is odd", |x: &i32| x % 2 == 1));
+///     ```
+///
+///     The most common comparisons can be written without importing a matcher.
+///     A literal in place of the `Matcher` is checked for equality.
+///
+///     ```rust,ignore
+///     assert_that!(&1, 1);
+///     assert_that!(&1, equals 1);
+///     assert_that!(&1, is less than 2);
+///     assert_that!(&1, is greater than 0);
+///     ```
+///
+///     A custom message, optionally with `format!`-style arguments, can be added to the failure output.
+///
+///     ```rust,ignore
+///     for i in 0..3 {
+///         assert_that!(&i, lt(3), "failed in iteration {}", i);
+///     }
+///     ```
 ///  3. Assert that some expression is expected to panic/not panic.
 ///
 ///     ```rust,ignore
 ///     assert_that!(panic!("panic"), panics);
 ///     assert_that!(1+1, does not panic);
 ///     ```
+///
+///     The message of the panic can be checked to contain an expected string.
+///
+///     ```rust,ignore
+///     assert_that!(panic!("index out of bounds"), panics with message "out of bounds");
+///     ```
+///
+///     The payload of the panic can be checked to be of an expected type, e.g., if it was raised with `std::panic::panic_any`.
+///
+///     ```rust,ignore
+///     assert_that!(std::panic::panic_any(MyError::Timeout), panics with type MyError);
+///     ```
+///
+///     The value of an expression which must not panic can be checked against a `Matcher`.
+///     The value is moved out of `std::panic::catch_unwind` and the `Matcher` borrows it.
+///
+///     ```rust,ignore
+///     assert_that!("42".parse::<i32>().unwrap(), returns eq(42));
+///     ```
+///
+///     As for all panic assertions the expression is evaluated with `std::panic::catch_unwind`,
+///     so everything it captures by reference must be `RefUnwindSafe`.
+///     Wrap it in `std::panic::AssertUnwindSafe` otherwise.
+///     The panic hook still runs before the payload is inspected, i.e., the default hook prints the panic to stderr.
 #[macro_export]
 macro_rules! assert_that {
     ( $actual: expr, panics ) => {{
-        let result = std::panic::catch_unwind(|| { $actual; });
+        let result = std::panic::catch_unwind(|| {
+            #[allow(clippy::diverging_sub_expression)]
+            let _ = $actual;
+        });
         if result.is_ok() {
             panic!("\nFailed assertion; expected expression to panic")
         }
     }};
+    ( $actual: expr, panics with message $expected: expr ) => {{
+        use galvanic_assert::color;
+        let expected = $expected;
+        let result = std::panic::catch_unwind(|| {
+            #[allow(clippy::diverging_sub_expression)]
+            let _ = $actual;
+        });
+        match result {
+            Ok(_) => panic!("\nFailed assertion; expected expression to panic with a message containing {:?}",
+                            expected),
+            Err(payload) => {
+                let message = payload.downcast_ref::<&str>().map(|msg| msg.to_string())
+                                     .or_else(|| payload.downcast_ref::<String>().cloned())
+                                     .map(|msg| color::strip(&msg));
+                match message {
+                    Some(ref msg) if msg.contains(expected) => { },
+                    Some(msg) => panic!("\nFailed assertion; expected expression to panic with a message containing {:?}\n    Got: {:?}",
+                                        expected, msg),
+                    None => panic!("\nFailed assertion; expected expression to panic with a message containing {:?}\n    Got: a panic payload which is not a string",
+                                   expected)
+                }
+            }
+        }
+    }};
+    ( $actual: expr, panics with type $expected: ty ) => {{
+        let result = std::panic::catch_unwind(|| {
+            #[allow(clippy::diverging_sub_expression)]
+            let _ = $actual;
+        });
+        match result {
+            Ok(_) => panic!("\nFailed assertion; expected expression to panic with a payload of type {}",
+                            stringify!($expected)),
+            Err(payload) => if !payload.is::<$expected>() {
+                panic!("\nFailed assertion; expected expression to panic with a payload of type {}\n    Got: a payload of another type",
+                       stringify!($expected))
+            }
+        }
+    }};
     ( $actual: expr, does not panic ) => {
-        let result = std::panic::catch_unwind(|| { $actual; });
+        let result = std::panic::catch_unwind(|| {
+            #[allow(clippy::diverging_sub_expression)]
+            let _ = $actual;
+        });
         if result.is_err() {
             panic!("\nFailed assertion; expression panicked unexpectantly")
         }
     };
+    ( $actual: expr, returns $matcher: expr ) => {{
+        #[allow(unused_imports)]
+        use galvanic_assert::{color, MatchResult, Matcher};
+        let result = std::panic::catch_unwind(|| $actual);
+        match result {
+            Ok(value) => {
+                // store matcher so it's dropped before the returned value
+                let m = $matcher;
+                match m.check(&value) {
+                    MatchResult::Matched { .. } => { },
+                    MatchResult::Failed { name, reason } => {
+                        panic!("\n{}", color::failure(&name, &reason))
+                    }
+                }
+            },
+            Err(_) => panic!("\nFailed assertion; expression panicked unexpectantly")
+        }
+    }};
     ( $actual: expr) => {{
         if !$actual {
             panic!("\nFailed assertion; '{}' is not true", stringify!($actual));
@@ -81,9 +204,21 @@ macro_rules! assert_that {
             );
         }
     }};
+    ( $actual: expr, $expected: literal ) => {
+        assert_that!($actual, galvanic_assert::matchers::ToMatcher::into_matcher($expected))
+    };
+    ( $actual: expr, equals $expected: expr ) => {
+        assert_that!($actual, galvanic_assert::matchers::equal_to($expected))
+    };
+    ( $actual: expr, is less than $expected: expr ) => {
+        assert_that!($actual, galvanic_assert::matchers::less_than($expected))
+    };
+    ( $actual: expr, is greater than $expected: expr ) => {
+        assert_that!($actual, galvanic_assert::matchers::greater_than($expected))
+    };
     ( $actual: expr, $matcher: expr ) => {{
         #[allow(unused_imports)]
-        use galvanic_assert::{MatchResult, Matcher};
+        use galvanic_assert::{color, MatchResult, Matcher};
         //use std::borrow::Borrow;
         // store the actual value to borrow it
         let value = $actual;
@@ -94,7 +229,215 @@ macro_rules! assert_that {
         match m.check(value) {
             MatchResult::Matched { .. } => { },
             MatchResult::Failed { name, reason } => {
-                panic!("\nFailed assertion of matcher: {}\n{}", name, reason)
+                panic!("\n{}", color::failure(&name, &reason))
+            }
+        }
+    }};
+    ( $actual: expr, $matcher: expr, $($message: tt)+ ) => {{
+        #[allow(unused_imports)]
+        use galvanic_assert::{color, MatchResult, Matcher};
+        let value = $actual;
+        let m = $matcher;
+        match m.check(value) {
+            MatchResult::Matched { .. } => { },
+            MatchResult::Failed { name, reason } => {
+                panic!("\n{}\n  Message: {}", color::failure(&name, &reason), format!($($message)+))
+            }
+        }
+    }};
+}
+
+/// States that the asserted value satisfies the required properties of the supplied `Matcher` without panicking.
+///
+/// Evaluates to `Ok(())` if the `Matcher` is satisfied and otherwise to an `Err` with the failure formatted as by `assert_that!`.
+/// The failure message is never colored, even with the `color` feature enabled, as it may end up in logs or other errors.
+/// Use it in tests returning a `Result`, e.g., `Result<(), Box<dyn Error>>`, to propagate a failure with `?`
+/// like the other errors of the test.
+/// Use `assert_that!` in all other tests and if the remainder of a test should not run after a failure anyway.
+///
+/// ```rust,ignore
+/// #[test]
+/// fn should_parse_config() -> Result<(), Box<dyn Error>> {
+///     let config = parse_config("retries = 3")?;
+///     try_assert_that!(&config.retries, eq(3))?;
+///     try_assert_that!(&config.name, is_none(), "parsed {:?}", config)?;
+///     Ok(())
+/// }
+/// ```
+#[macro_export]
+macro_rules! try_assert_that {
+    ( $actual: expr, $matcher: expr ) => {{
+        #[allow(unused_imports)]
+        use galvanic_assert::Matcher;
+        let value = $actual;
+        let m = $matcher;
+        let result: Result<(), String> = m.check(value).into();
+        result
+    }};
+    ( $actual: expr, $matcher: expr, $($message: tt)+ ) => {{
+        #[allow(unused_imports)]
+        use galvanic_assert::Matcher;
+        let value = $actual;
+        let m = $matcher;
+        let result: Result<(), String> = m.check(value).into();
+        result.map_err(|failure| format!("{}\n  Message: {}", failure, format!($($message)+)))
+    }};
+}
+
+/// States that the asserted value satisfies the required properties of the supplied `Matcher` and returns it.
+///
+/// In contrast to `assert_that!` the value is passed by value and handed back on success,
+/// so it can be used further after the assertion.
+/// The `Matcher` only borrows the value while checking it.
+/// This is a macro and not a function as the matchers borrow the value for a lifetime chosen by the caller.
+///
+/// ```rust,ignore
+/// let parsed = expect!("42".parse::<i32>(), is_ok());
+/// let name = expect!(user.name, starts_with("A"));
+/// ```
+#[macro_export]
+macro_rules! expect {
+    ( $actual: expr, $matcher: expr ) => {{
+        let value = $actual;
+        assert_that!(&value, $matcher);
+        value
+    }};
+}
+
+/// States that each of the asserted values satisfies the required properties of its `Matcher`.
+///
+/// In contrast to multiple `assert_that!` statements all assertions are checked before panicking.
+/// The panic message reports how many assertions were checked and includes every failure.
+///
+/// ```rust,ignore
+/// assert_all!(
+///     (&1, eq(1)),
+///     (&2, lt(2)),
+///     (&vec![1,2], contains_in_order(vec![2,1]))
+/// ); // panics with "2 of 3 assertions failed" and the failures of both matchers
+/// ```
+#[macro_export]
+macro_rules! assert_all {
+    ( $( ( $actual: expr, $matcher: expr ) ),+ $(,)* ) => {{
+        #[allow(unused_imports)]
+        use galvanic_assert::{color, MatchResult, Matcher};
+        let mut checked = 0;
+        let mut failures: Vec<String> = Vec::new();
+        $({
+            checked += 1;
+            let value = $actual;
+            let m = $matcher;
+            if let MatchResult::Failed { name, reason } = m.check(value) {
+                failures.push(format!("Assertion {}: '{}' matches '{}'\n{}",
+                                      checked, stringify!($actual), stringify!($matcher),
+                                      color::failure(&name, &reason)));
+            }
+        })+
+        if !failures.is_empty() {
+            panic!("\n{} of {} assertions failed\n\n{}", failures.len(), checked, failures.join("\n\n"));
+        }
+    }};
+}
+
+/// States that a function panics for each of the bad inputs and does not panic for each of the good inputs.
+///
+/// Every input is checked before panicking; the panic message reports each input violating its expectation.
+/// The list of good inputs is optional.
+/// As for the panic assertions of `assert_that!` the function is called within `std::panic::catch_unwind`.
+///
+/// ```rust,ignore
+/// assert_panics_for!(|divisor: i32| 10 / divisor,
+///                    panics for [0],
+///                    does not panic for [1, 2, -5]);
+/// ```
+#[macro_export]
+macro_rules! assert_panics_for {
+    ( $function: expr, panics for [ $($bad: expr),* $(,)* ] ) => {
+        assert_panics_for!($function, panics for [ $($bad),* ], does not panic for [])
+    };
+    ( $function: expr, panics for [ $($bad: expr),* $(,)* ], does not panic for [ $($good: expr),* $(,)* ] ) => {{
+        let function = $function;
+        let mut checked = 0;
+        let mut violations: Vec<String> = Vec::new();
+        $(
+            checked += 1;
+            if std::panic::catch_unwind(|| function($bad)).is_ok() {
+                violations.push(format!("  '{}' did not panic", stringify!($bad)));
+            }
+        )*
+        $(
+            checked += 1;
+            if std::panic::catch_unwind(|| function($good)).is_err() {
+                violations.push(format!("  '{}' panicked unexpectantly", stringify!($good)));
+            }
+        )*
+        if !violations.is_empty() {
+            panic!("\nFailed assertion; {} of {} inputs violated their expectation\n{}",
+                   violations.len(), checked, violations.join("\n"));
+        }
+    }};
+}
+
+/// States that the values produced by a closure eventually satisfy the required properties of the supplied `Matcher`.
+///
+/// The closure is called repeatedly, sleeping `interval` between the attempts, until the produced value matches.
+/// If it still does not match after the `timeout` has elapsed the assertion panics with the last failure.
+/// The matcher expression is evaluated anew for every attempt.
+/// This is useful to poll for asynchronous side effects, e.g., in the file system or of other threads.
+///
+/// ```rust,ignore
+/// eventually!(Duration::from_secs(1), Duration::from_millis(10), || receiver.try_recv().ok(), maybe_some(eq(42)));
+/// ```
+#[macro_export]
+macro_rules! eventually {
+    ( $timeout: expr, $interval: expr, $produce: expr, $matcher: expr ) => {{
+        #[allow(unused_imports)]
+        use galvanic_assert::{color, MatchResult, Matcher};
+        let timeout: std::time::Duration = $timeout;
+        let interval: std::time::Duration = $interval;
+        let produce = $produce;
+        let start = std::time::Instant::now();
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let value = produce();
+            let m = $matcher;
+            if let MatchResult::Failed { name, reason } = m.check(&value) {
+                if start.elapsed() >= timeout {
+                    panic!("\nFailed assertion; no produced value matched within {:?} ({} attempts)\n{}",
+                           timeout, attempts, color::failure(&name, &reason))
+                }
+                std::thread::sleep(interval);
+            } else {
+                break;
+            }
+        }
+    }};
+}
+
+/// States that each of `n` values produced by a closure satisfies the required properties of the supplied `Matcher`.
+///
+/// The closure is called `n` times, e.g., to draw random samples, and the assertion panics with the first failing sample.
+/// The panic message reports the sample's debug representation and the number of the iteration it was produced in.
+/// The matcher expression is evaluated anew for every sample.
+/// This is a macro and not a function as the matchers borrow the samples for a lifetime chosen by the caller.
+///
+/// ```rust,ignore
+/// for_samples!(100, || rng.gen_range(0..10), lt(10));
+/// ```
+#[macro_export]
+macro_rules! for_samples {
+    ( $n: expr, $produce: expr, $matcher: expr ) => {{
+        #[allow(unused_imports)]
+        use galvanic_assert::{color, MatchResult, Matcher};
+        let n: usize = $n;
+        let produce = $produce;
+        for iteration in 0..n {
+            let sample = produce();
+            let m = $matcher;
+            if let MatchResult::Failed { name, reason } = m.check(&sample) {
+                panic!("\nFailed assertion; sample {:?} produced in iteration {} of {} does not match\n{}",
+                       sample, iteration, n, color::failure(&name, &reason))
             }
         }
     }};
@@ -154,7 +497,10 @@ macro_rules! assert_that {
 macro_rules! get_expectation_for {
     ( $actual: expr, panics ) => {{
         use galvanic_assert::Expectation;
-        let result = std::panic::catch_unwind(|| { $actual; });
+        let result = std::panic::catch_unwind(|| {
+            #[allow(clippy::diverging_sub_expression)]
+            let _ = $actual;
+        });
         if result.is_ok() {
             let assertion = format!("'{}, panics'", stringify!($actual));
             Expectation::failed(assertion, file!().to_string(), line!(),
@@ -166,7 +512,10 @@ macro_rules! get_expectation_for {
     }};
     ( $actual: expr, does not panic ) => {{
         use galvanic_assert::Expectation;
-        let result = std::panic::catch_unwind(|| { $actual; });
+        let result = std::panic::catch_unwind(|| {
+            #[allow(clippy::diverging_sub_expression)]
+            let _ = $actual;
+        });
         if result.is_err() {
             let assertion = format!("'{}, does not panic'", stringify!($actual));
             Expectation::failed(assertion, file!().to_string(), line!(),
@@ -195,7 +544,7 @@ macro_rules! get_expectation_for {
     }};
     ( $actual: expr, $matcher: expr ) => {{
         #[allow(unused_imports)]
-        use galvanic_assert::{Expectation, MatchResult, Matcher};
+        use galvanic_assert::{color, Expectation, MatchResult, Matcher};
         let value = $actual;
         let m = $matcher;
         match m.check(value) {
@@ -203,7 +552,7 @@ macro_rules! get_expectation_for {
             MatchResult::Failed { name, reason } => {
                 let assertion = format!("'{}' matches '{}'", stringify!($actual), stringify!($matcher));
                 Expectation::failed(assertion, file!().to_string(), line!(),
-                                    format!("Failed assertion of matcher: {}\n{}", name, reason)
+                                    color::failure(&name, &reason)
                 )
             }
         }
@@ -261,24 +610,64 @@ macro_rules! expect_that {
     ( $actual: expr, $matcher: expr ) => { #[allow(unused_variables)] let expectation = get_expectation_for!($actual, $matcher); };
 }
 
+/// Checks if the `actual` value satisfies the required properties of the supplied `Matcher` without panicking.
+///
+/// The `MatchResult` of the check is returned for further inspection.
+/// This is the building block of the assertion macros:
+/// `assert_that!(actual, matcher)` is effectively sugar for checking the result of this function and panicking if it failed.
+/// Use it to write retry loops or to aggregate the results of several matchers.
+///
+/// # Examples
+/// ```rust,ignore
+/// match check_that(&1, eq(1)) {
+///     MatchResult::Matched { .. } => println!("matched"),
+///     MatchResult::Failed { name, reason } => println!("{} failed:\n{}", name, reason)
+/// }
+/// ```
+///
+/// The result converts into a `Result<(), String>`, so test helpers can return early on the first failure.
+///
+/// ```rust,ignore
+/// fn check_user(user: &User) -> Result<(), String> {
+///     Result::from(check_that(&user.age, gt(18)))?;
+///     Result::from(check_that(&user.name, starts_with("A")))?;
+///     Ok(())
+/// }
+/// ```
+pub fn check_that<'a, T:'a + ?Sized, M>(actual: &'a T, matcher: M) -> MatchResult
+where M: Matcher<'a,T> {
+    matcher.check(actual)
+}
+
 /// The trait which has to be implemented by all matchers.
-pub trait Matcher<'a, T:'a> {
+pub trait Matcher<'a, T:'a + ?Sized> {
     /// Checks the passed value if it satisfies the `Matcher`.
     ///
     /// Values are always taken as immutable reference as the actual value shouldn't be changed by the matcher.
+    /// The matcher itself is borrowed immutably as well, so that it can be checked repeatedly, e.g., when combined with other matchers.
     fn check(&self, actual: &'a T) -> MatchResult;
 }
 
 /// A closures can be used as a `Matcher`.
 ///
-/// The closure must be repeatably callable in case that the matcher is combined with another matcher.
-impl<'a, T:'a, F> Matcher<'a,T> for F
+/// The closure must be repeatably callable in case that the matcher is combined with another matcher,
+/// i.e., it has to implement `Fn` and not only `FnMut` or `FnOnce`.
+impl<'a, T:'a + ?Sized, F> Matcher<'a,T> for F
 where F: Fn(&'a T) -> MatchResult + ?Sized {
     fn check(&self, actual: &'a T) -> MatchResult {
         self(actual)
     }
 }
 
+/// A boxed `Matcher` is a `Matcher` itself.
+///
+/// This allows to pass the boxed matchers returned by the predefined matcher functions wherever a generic `Matcher` is expected.
+impl<'a, T:'a + ?Sized> Matcher<'a,T> for Box<dyn Matcher<'a,T> + 'a> {
+    fn check(&self, actual: &'a T) -> MatchResult {
+        (**self).check(actual)
+    }
+}
+
 /// The return type of any `Machter`
 pub enum MatchResult {
     /// Indicates that the `Matcher` matched the value under inspection.
@@ -295,14 +684,95 @@ pub enum MatchResult {
     }
 }
 
+impl MatchResult {
+    /// Returns `true` if the `Matcher` matched the value under inspection.
+    pub fn is_matched(&self) -> bool {
+        match *self {
+            MatchResult::Matched { .. } => true,
+            MatchResult::Failed { .. } => false
+        }
+    }
+
+    /// Returns `true` if the `Matcher` failed to match the value under inspection.
+    pub fn is_failed(&self) -> bool {
+        !self.is_matched()
+    }
+
+    /// Returns the `name` of the `Matcher` which produced this result.
+    pub fn name(&self) -> &str {
+        match *self {
+            MatchResult::Matched { ref name } | MatchResult::Failed { ref name, .. } => name
+        }
+    }
+
+    /// Returns the `reason` why the `Matcher` failed or `None` if it matched.
+    pub fn reason(&self) -> Option<&str> {
+        match *self {
+            MatchResult::Matched { .. } => None,
+            MatchResult::Failed { ref reason, .. } => Some(reason)
+        }
+    }
+
+    /// Returns `self` if it failed, otherwise evaluates `f` and returns its result.
+    ///
+    /// This is the value-level equivalent of `all_of!`.
+    pub fn and_then<F>(self, f: F) -> MatchResult
+    where F: FnOnce() -> MatchResult {
+        match self {
+            MatchResult::Matched { .. } => f(),
+            failed => failed
+        }
+    }
+
+    /// Returns `self` if it matched, otherwise evaluates `f` and returns its result.
+    ///
+    /// This is the value-level equivalent of `any_of!`.
+    pub fn or_else<F>(self, f: F) -> MatchResult
+    where F: FnOnce() -> MatchResult {
+        match self {
+            MatchResult::Failed { .. } => f(),
+            matched => matched
+        }
+    }
+}
+
+/// A matched result is displayed as the name of the `Matcher`,
+/// a failed result in the same way as the failure message of `assert_that!`.
+impl Display for MatchResult {
+    fn fmt(&self, f: &mut Formatter) -> FormatResult {
+        match *self {
+            MatchResult::Matched { ref name } => write!(f, "{}", name),
+            MatchResult::Failed { ref name, ref reason } => write!(f, "Failed assertion of matcher: {}\n{}", name, reason)
+        }
+    }
+}
+
+/// Converts a `MatchResult` into a `Result` so it can be propagated with the `?` operator.
+///
+/// A matched result becomes `Ok(())`, a failed result an `Err` holding the failure message.
+impl From<MatchResult> for Result<(), String> {
+    fn from(result: MatchResult) -> Result<(), String> {
+        match result {
+            MatchResult::Matched { .. } => Ok(()),
+            failed => Err(failed.to_string())
+        }
+    }
+}
+
 /// A builder for creating `MatchResult`s.
 ///
 /// Create a new builder with `new()` or `for_()`
-/// and finalize it either with `matched()`, `failed_because()`, or `failed_comparison()`.
+/// and finalize it either with `matched()`, `failed_because()`, `failed_because_of()`, `failed_comparison()`, `failed_comparison_labeled()`, or `failed_diff()`.
 pub struct MatchResultBuilder {
     matcher_name: String
 }
 
+impl Default for MatchResultBuilder {
+    fn default() -> MatchResultBuilder {
+        MatchResultBuilder::new()
+    }
+}
+
 impl MatchResultBuilder {
     /// Creates a `MatchResultBuilder` for an anonymous `Matcher`.
     pub fn new() -> MatchResultBuilder {
@@ -333,6 +803,19 @@ impl MatchResultBuilder {
         }
     }
 
+    /// Finalzes the builder indicating that the `Matcher` failed because the `result` of a nested `Matcher`
+    /// applied to the `subject`, i.e., the inspected part of the value, failed.
+    ///
+    /// The name and reason of the nested failure are embedded in the reason, the nested reason being indented.
+    /// A nested `Matched` result is reported as being satisfied unexpectedly.
+    pub fn failed_because_of(self, subject: &str, result: MatchResult) -> MatchResult {
+        match result {
+            MatchResult::Matched { name } => self.failed_because(&format!("{} unexpectedly matches {}", subject, name)),
+            MatchResult::Failed { name, reason } =>
+                self.failed_because(&format!("{} does not match {}:\n  {}", subject, name, reason.replace("\n", "\n  ")))
+        }
+    }
+
     /// Finalzes the builder indicating that the `Matcher` failed to the inspected value.
     ///
     /// The `actual` and `expected` value are used the generate a useful error message.
@@ -342,6 +825,53 @@ impl MatchResultBuilder {
             reason: format!("  Expected: {:?}\n  Got: {:?}", expected, actual)
         }
     }
+
+    /// Finalzes the builder indicating that the `Matcher` failed to the inspected value.
+    ///
+    /// Works like `failed_comparison()` but reports the `expected` value under the given `expected_label`
+    /// and the `actual` value under the `actual_label`, e.g., "Bound" for the limit of an ordering matcher.
+    pub fn failed_comparison_labeled<T: Debug>(self, actual: &T, actual_label: &str,
+                                               expected: &T, expected_label: &str) -> MatchResult {
+        MatchResult::Failed {
+            name: self.matcher_name,
+            reason: format!("  {}: {:?}\n  {}: {:?}", expected_label, expected, actual_label, actual)
+        }
+    }
+
+    /// Finalzes the builder indicating that the `Matcher` failed to the inspected sequence of values.
+    ///
+    /// If both sequences have the same length only the first differing index and the elements at that index are reported.
+    /// Otherwise the whole `actual` and `expected` sequences are shown as with `failed_comparison()`.
+    pub fn failed_diff<T: PartialEq + Debug>(self, actual: &[T], expected: &[T]) -> MatchResult {
+        let first_difference = actual.iter().zip(expected.iter()).position(|(act, exp)| act != exp);
+        match first_difference {
+            Some(index) if actual.len() == expected.len() => MatchResult::Failed {
+                name: self.matcher_name,
+                reason: format!("  First difference at index {}\n  Expected: {:?}\n  Got: {:?}",
+                                index, expected[index], actual[index])
+            },
+            _ => self.failed_comparison(&actual, &expected)
+        }
+    }
+}
+
+/// Creates a `MatchResult` indicating that the `Matcher` with the given `name` matched the inspected value.
+///
+/// A shortcut for `MatchResultBuilder::for_(name).matched()` when writing matchers by hand.
+///
+/// ```rust,ignore
+/// assert_that!(&2, |x: &i32| if x % 2 == 0 { matched("even") } else { failed("even", "the value is odd") });
+/// ```
+pub fn matched(name: &str) -> MatchResult {
+    MatchResultBuilder::for_(name).matched()
+}
+
+/// Creates a `MatchResult` indicating that the `Matcher` with the given `name` failed for the given `reason`.
+///
+/// A shortcut for `MatchResultBuilder::for_(name).failed_because(reason)` when writing matchers by hand.
+/// Use the `MatchResultBuilder` to report failed comparisons.
+pub fn failed(name: &str, reason: &str) -> MatchResult {
+    MatchResultBuilder::for_(name).failed_because(reason)
 }
 
 /// The result of a deferred assertion.
@@ -365,10 +895,10 @@ impl Expectation {
     /// Creates a failed `Expectation`
     pub fn failed(assertion:String, file: String, line: u32, error_msg: String) -> Expectation {
         Expectation::Failed {
-            assertion: assertion,
-            file: file,
-            line: line,
-            error_msg: error_msg
+            assertion,
+            file,
+            line,
+            error_msg
         }
     }
 
@@ -398,7 +928,7 @@ impl Drop for Expectation {
 impl Display for Expectation {
     fn fmt(&self, f: &mut Formatter) -> FormatResult {
         match self {
-            &Expectation::Failed { ref assertion, ref file, ref line, ref error_msg } => {
+            Expectation::Failed { assertion, file, line, error_msg } => {
                 write!(f, "Expectation '{}' failed, originating from {}:{}\n\t{}",
                        assertion, file, line, error_msg
                 )
@@ -408,4 +938,6 @@ impl Display for Expectation {
     }
 }
 
+#[doc(hidden)]
+pub mod color;
 pub mod matchers;
@@ -26,7 +26,7 @@ pub struct ContainsInAnyOrder<T> {
 }
 
 /// Matches if the asserted collection contains *all and only* of the expected elements in any order.
-pub fn contains_in_any_order<'a,T:'a,I:'a>(expected_elements: I) -> Box<Matcher<'a,I> + 'a>
+pub fn contains_in_any_order<'a,T:'a,I:'a>(expected_elements: I) -> Box<dyn Matcher<'a,I> + 'a>
 where T: PartialEq + Debug,
       I: IntoIterator<Item=T>,
       ContainsInAnyOrder<T>: Matcher<'a,I> {
@@ -42,34 +42,52 @@ where T: PartialEq + Debug + 'a,
         let repr = format!("{:?}", actual);
         let builder = MatchResultBuilder::for_("contains_in_any_order");
         let mut expected_elements = Vec::from_iter(self.expected_elements.iter());
+        let mut unexpected_elements = Vec::new();
 
         for ref element in actual.into_iter() {
             let maybe_pos = expected_elements.iter()
-                                             .position(|candidate| element == candidate);;
+                                             .position(|candidate| element == candidate);
             if let Some(idx) = maybe_pos {
                 expected_elements.remove(idx);
             } else {
-                return builder.failed_because(
-                    &format!("{} contains an unexpected element: {:?}", repr, element)
-                );
+                unexpected_elements.push(*element);
             }
         }
 
-        if !expected_elements.is_empty() {
-            builder.failed_because(
+        match (expected_elements.is_empty(), unexpected_elements.is_empty()) {
+            (true, true) => builder.matched(),
+            (false, true) => builder.failed_because(
                 &format!("{} did not contain the following elements: {:?}", repr, expected_elements)
+            ),
+            (true, false) => builder.failed_because(
+                &format!("{} contains unexpected elements: {:?}", repr, unexpected_elements)
+            ),
+            (false, false) => builder.failed_because(
+                &format!("{} did not contain the following elements: {:?}\n\tand contains unexpected elements: {:?}",
+                         repr, expected_elements, unexpected_elements)
             )
-        } else { builder.matched() }
+        }
     }
 }
 
+/// Matches if the asserted collection contains *all and only* the expected elements in any order.
+///
+/// The collections are compared as multisets, i.e., each expected element has to occur exactly as often as expected.
+/// This is the same as [contains_in_any_order].
+pub fn contains_exactly<'a,T:'a,I:'a>(expected_elements: I) -> Box<dyn Matcher<'a,I> + 'a>
+where T: PartialEq + Debug,
+      I: IntoIterator<Item=T>,
+      ContainsInAnyOrder<T>: Matcher<'a,I> {
+    contains_in_any_order(expected_elements)
+}
+
 /// Matches if the asserted collection contains *all and only* of the expected elements in the given order.
 pub struct ContainsInOrder<T> {
     expected_elements: Vec<T>
 }
 
 /// Matches if the asserted collection contains *all and only* of the expected elements in the given order.
-pub fn contains_in_order<'a,T:'a,I:'a>(expected_elements: I) -> Box<Matcher<'a,I> + 'a>
+pub fn contains_in_order<'a,T:'a,I:'a>(expected_elements: I) -> Box<dyn Matcher<'a,I> + 'a>
 where T: PartialEq + Debug,
       I: IntoIterator<Item=T>,
       ContainsInOrder<T>: Matcher<'a,I> {
@@ -111,13 +129,94 @@ where T: PartialEq + Debug + 'a,
     }
 }
 
+/// Matches if the asserted collection is element-wise equal to the expected elements.
+///
+/// In contrast to `equal_to` a failure only reports the first differing index
+/// and the elements at that index if both collections have the same length.
+pub fn equal_to_slices<'a,T:'a,I:'a>(expected_elements: I) -> Box<dyn Matcher<'a,I> + 'a>
+where T: PartialEq + Debug,
+      I: IntoIterator<Item=T>,
+      &'a I: IntoIterator<Item=&'a T> {
+    let expected_elements: Vec<T> = expected_elements.into_iter().collect();
+    Box::new(move |actual: &'a I| {
+        let builder = MatchResultBuilder::for_("equal_to_slices");
+        let actual_list: Vec<_> = actual.into_iter().collect();
+        let expected_list: Vec<_> = expected_elements.iter().collect();
+        if actual_list == expected_list {
+            builder.matched()
+        } else {
+            builder.failed_diff(&actual_list, &expected_list)
+        }
+    })
+}
+
+/// Matches if the projections of the asserted collection's elements are element-wise equal to those of the expected elements.
+///
+/// Use it to ignore irrelevant parts of the elements, e.g., timestamps.
+/// A failure reports the first index whose projections differ if both collections have the same length, see `equal_to_slices`.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&events, equal_to_projected(expected_events, |e: &Event| (e.kind, e.payload.clone())));
+/// ```
+pub fn equal_to_projected<'a,T:'a,I:'a + ?Sized,K,P>(expected_elements: Vec<T>, project: P) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator<Item=&'a T> + 'a,
+      K: PartialEq + Debug,
+      P: Fn(&T) -> K + 'a {
+    Box::new(move |actual: &'a I| {
+        let builder = MatchResultBuilder::for_("equal_to_projected");
+        let actual_projections: Vec<K> = actual.into_iter().map(&project).collect();
+        let expected_projections: Vec<K> = expected_elements.iter().map(&project).collect();
+        if actual_projections == expected_projections {
+            builder.matched()
+        } else {
+            builder.failed_diff(&actual_projections, &expected_projections)
+        }
+    })
+}
+
+/// Matches if the asserted collection of floating point values is element-wise close to the expected elements.
+///
+/// Both collections must have the same length and each actual element may differ from
+/// the expected element at the same index by at most `eps`.
+/// A failure reports the first index whose difference exceeds `eps`.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&vec![0.1 + 0.2, 1.0], close_to_elements(vec![0.3, 1.0], 1e-9));
+/// ```
+pub fn close_to_elements<'a,I:'a + ?Sized>(expected_elements: Vec<f64>, eps: f64) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator<Item=&'a f64> + 'a {
+    Box::new(move |actual: &'a I| {
+        let builder = MatchResultBuilder::for_("close_to_elements");
+        let actual_list: Vec<f64> = actual.into_iter().cloned().collect();
+        if actual_list.len() != expected_elements.len() {
+            return builder.failed_because(
+                &format!("lengths differ\n\tExpected length: {}\n\tActual length: {}",
+                         expected_elements.len(), actual_list.len())
+            );
+        }
+        let first_difference = actual_list.iter().zip(expected_elements.iter()).position(|(act, exp)| {
+            let within = (act - exp).abs() <= eps;
+            !within
+        });
+        match first_difference {
+            Some(index) => builder.failed_because(
+                &format!("elements at index {} differ by more than {:?}\n\tExpected: {:?}\n\tGot: {:?}",
+                         index, eps, expected_elements[index], actual_list[index])
+            ),
+            None => builder.matched()
+        }
+    })
+}
+
 /// Matches if the asserted collection contains *all* (possibly more) of the expected elements.
 pub struct ContainsSubset<T> {
     expected_elements: Vec<T>
 }
 
 /// Matches if the asserted collection contains *all* (possibly more) of the expected elements.
-pub fn contains_subset<'a,T:'a,I:'a>(expected_elements: I) -> Box<Matcher<'a,I> + 'a>
+pub fn contains_subset<'a,T:'a,I:'a>(expected_elements: I) -> Box<dyn Matcher<'a,I> + 'a>
 where T: PartialEq + Debug,
       I: IntoIterator<Item=T>,
       ContainsSubset<T>: Matcher<'a,I> {
@@ -150,13 +249,122 @@ where T: PartialEq + Debug + 'a,
     }
 }
 
+/// Matches if the asserted collection contains *all* (possibly more) of the expected elements in the given relative order.
+///
+/// The expected elements need not be adjacent in the asserted collection.
+pub struct ContainsSubsequence<T> {
+    expected_elements: Vec<T>
+}
+
+/// Matches if the asserted collection contains *all* (possibly more) of the expected elements in the given relative order.
+///
+/// The expected elements need not be adjacent in the asserted collection.
+/// Each expected element is greedily matched with the next equal element of the collection.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&vec![1,2,3,4,5], contains_subsequence(vec![2,4,5]));
+/// ```
+pub fn contains_subsequence<'a,T:'a,I:'a>(expected_elements: I) -> Box<dyn Matcher<'a,I> + 'a>
+where T: PartialEq + Debug,
+      I: IntoIterator<Item=T>,
+      ContainsSubsequence<T>: Matcher<'a,I> {
+    Box::new(ContainsSubsequence {
+        expected_elements: expected_elements.into_iter().collect()
+    })
+}
+
+impl<'a, T, I:'a> Matcher<'a,I> for ContainsSubsequence<T>
+where T: PartialEq + Debug + 'a,
+      &'a I: IntoIterator<Item=&'a T> + Debug + 'a {
+    fn check(&self, actual: &'a I) -> MatchResult {
+        let repr = format!("{:?}", actual);
+        let builder = MatchResultBuilder::for_("contains_subsequence");
+        let mut remaining = actual.into_iter().enumerate();
+        let mut position = 0;
+
+        for expected in self.expected_elements.iter() {
+            match remaining.find(|&(_, element)| element == expected) {
+                Some((idx, _)) => position = idx + 1,
+                None => return builder.failed_because(
+                    &format!("{} does not contain {:?} at or after index {}", repr, expected, position)
+                )
+            }
+        }
+        builder.matched()
+    }
+}
+
+/// Matches if the asserted collection contains the expected element.
+///
+/// Any collection which can be iterated by reference is accepted, including slices.
+///
+/// # Examples
+/// ```rust,ignore
+/// let slice: &[i32] = &[1,2,3];
+/// assert_that!(slice, contains(2));
+/// ```
+pub fn contains<'a,T:'a,I:'a + ?Sized>(expected_element: T) -> Box<dyn Matcher<'a,I> + 'a>
+where T: PartialEq + Debug,
+      &'a I: IntoIterator<Item=&'a T> + Debug + 'a {
+    Box::new(move |elements: &'a I| {
+        let builder = MatchResultBuilder::for_("contains");
+        if elements.into_iter().any(|element| element == &expected_element) {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} does not contain: {:?}", elements, expected_element))
+        }
+    })
+}
+
+/// Matches if the asserted collection starts with the elements of the expected `prefix`.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&vec![0xCA, 0xFE, 0x01], starts_with_elements(vec![0xCA, 0xFE]));
+/// ```
+pub fn starts_with_elements<'a,T:'a,I:'a + ?Sized>(prefix: Vec<T>) -> Box<dyn Matcher<'a,I> + 'a>
+where T: PartialEq + Debug,
+      &'a I: IntoIterator<Item=&'a T> + 'a {
+    Box::new(move |elements: &'a I| {
+        let builder = MatchResultBuilder::for_("starts_with_elements");
+        let actual_prefix: Vec<_> = elements.into_iter().take(prefix.len()).collect();
+        if actual_prefix.len() == prefix.len() && actual_prefix.iter().zip(prefix.iter()).all(|(act, exp)| *act == exp) {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("expected prefix {:?} but the collection starts with {:?}", prefix, actual_prefix))
+        }
+    })
+}
+
+/// Matches if the asserted collection ends with the elements of the expected `suffix`.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&vec![0x01, 0x0D, 0x0A], ends_with_elements(vec![0x0D, 0x0A]));
+/// ```
+pub fn ends_with_elements<'a,T:'a,I:'a + ?Sized>(suffix: Vec<T>) -> Box<dyn Matcher<'a,I> + 'a>
+where T: PartialEq + Debug,
+      &'a I: IntoIterator<Item=&'a T> + 'a {
+    Box::new(move |elements: &'a I| {
+        let builder = MatchResultBuilder::for_("ends_with_elements");
+        let actual_list: Vec<_> = elements.into_iter().collect();
+        let actual_suffix = &actual_list[actual_list.len().saturating_sub(suffix.len())..];
+        if actual_suffix.len() == suffix.len() && actual_suffix.iter().zip(suffix.iter()).all(|(act, exp)| *act == exp) {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("expected suffix {:?} but the collection ends with {:?}", suffix, actual_suffix))
+        }
+    })
+}
+
 /// Matches if the asserted (single) value is contained in the expected elements.
 pub struct ContainedIn<T> {
     expected_to_contain: Vec<T>
 }
 
 /// Matches if the asserted (single) value is contained in the expected elements.
-pub fn contained_in<'a,T:'a,I>(expected_to_contain: I) -> Box<Matcher<'a,T> + 'a>
+pub fn contained_in<'a,T:'a,I>(expected_to_contain: I) -> Box<dyn Matcher<'a,T> + 'a>
 where T: PartialEq + Debug,
       I: IntoIterator<Item=T> {
     Box::new(ContainedIn {
@@ -168,7 +376,7 @@ impl<'a,T> Matcher<'a,T> for ContainedIn<T>
 where T: PartialEq + Debug + 'a  {
     fn check(&self, element: &T) -> MatchResult {
         let builder = MatchResultBuilder::for_("containd_in");
-        if let None = self.expected_to_contain.iter().position(|e| e == element) {
+        if self.expected_to_contain.iter().position(|e| e == element).is_none() {
             builder.failed_because(
                 &format!("{:?} does not contain: {:?}", self.expected_to_contain, element)
             )
@@ -176,12 +384,252 @@ where T: PartialEq + Debug + 'a  {
     }
 }
 
+/// Matches if every element of the asserted collection is contained in the expected `superset`.
+///
+/// The elements are compared as sets, i.e., duplicates in the asserted collection are allowed.
+/// Only `PartialEq` is required of the elements.
+pub fn is_subset_of<'a,T:'a,I:'a + ?Sized>(superset: Vec<T>) -> Box<dyn Matcher<'a,I> + 'a>
+where T: PartialEq + Debug,
+      &'a I: IntoIterator<Item=&'a T> + 'a {
+    Box::new(move |elements: &'a I| {
+        let builder = MatchResultBuilder::for_("is_subset_of");
+        let not_found: Vec<_> = elements.into_iter().filter(|element| !superset.contains(element)).collect();
+        if not_found.is_empty() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the following elements are not contained in {:?}: {:?}", superset, not_found))
+        }
+    })
+}
+
+/// Matches if every element of the expected `subset` is contained in the asserted collection.
+///
+/// The elements are compared as sets, i.e., duplicates in the `subset` are allowed.
+/// Only `PartialEq` is required of the elements.
+pub fn is_superset_of<'a,T:'a,I:'a + ?Sized>(subset: Vec<T>) -> Box<dyn Matcher<'a,I> + 'a>
+where T: PartialEq + Debug,
+      &'a I: IntoIterator<Item=&'a T> + 'a {
+    Box::new(move |elements: &'a I| {
+        let builder = MatchResultBuilder::for_("is_superset_of");
+        let actual_elements: Vec<_> = elements.into_iter().collect();
+        let missing: Vec<_> = subset.iter().filter(|element| !actual_elements.contains(element)).collect();
+        if missing.is_empty() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the following elements are missing: {:?}", missing))
+        }
+    })
+}
+
+/// Matches if the asserted collection contains all of the `required` elements, ignoring extra elements and the order.
+///
+/// An alias of [is_superset_of] reading naturally for result sets; the failure lists the missing elements.
+pub fn contains_all<'a,T:'a,I:'a + ?Sized>(required: Vec<T>) -> Box<dyn Matcher<'a,I> + 'a>
+where T: PartialEq + Debug,
+      &'a I: IntoIterator<Item=&'a T> + 'a {
+    is_superset_of(required)
+}
+
+/// Matches if the asserted collection contains none of the `forbidden` elements.
+///
+/// The failure lists each forbidden element found together with the indices at which it occurs.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&sanitized_words, contains_none(vec!["<script>", "DROP TABLE"]));
+/// ```
+pub fn contains_none<'a,T:'a,I:'a + ?Sized>(forbidden: Vec<T>) -> Box<dyn Matcher<'a,I> + 'a>
+where T: PartialEq + Debug,
+      &'a I: IntoIterator<Item=&'a T> + 'a {
+    Box::new(move |elements: &'a I| {
+        let builder = MatchResultBuilder::for_("contains_none");
+        let actual_elements: Vec<_> = elements.into_iter().collect();
+        let found: Vec<String> = forbidden.iter().filter_map(|element| {
+            let indices: Vec<_> = actual_elements.iter()
+                                                 .enumerate()
+                                                 .filter(|&(_, actual)| *actual == element)
+                                                 .map(|(index, _)| index)
+                                                 .collect();
+            if indices.is_empty() {
+                None
+            } else {
+                Some(format!("\n\t{:?} at indices {:?}", element, indices))
+            }
+        }).collect();
+        if found.is_empty() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the following forbidden elements are present:{}", found.concat()))
+        }
+    })
+}
+
+/// Matches if the asserted collection contains exactly the expected number of elements.
+///
+/// The length is determined by iterating over the collection.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&vec![1,2,3], has_length(3));
+/// ```
+pub fn has_length<'a,I:'a + ?Sized>(expected_length: usize) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator + 'a {
+    Box::new(move |elements: &'a I| {
+        let builder = MatchResultBuilder::for_("has_length");
+        let actual_length = elements.into_iter().count();
+        if actual_length != expected_length {
+            builder.failed_because(
+                &format!("expected length {} but got {}", expected_length, actual_length)
+            )
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if the number of elements in the asserted collection is within `[low, high]`.
+///
+/// The length is determined by iterating over the collection.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&page, has_length_between(1, 20));
+/// ```
+pub fn has_length_between<'a,I:'a + ?Sized>(low: usize, high: usize) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator + 'a {
+    Box::new(move |elements: &'a I| {
+        let builder = MatchResultBuilder::for_("has_length_between");
+        let actual_length = elements.into_iter().count();
+        if actual_length < low || actual_length > high {
+            builder.failed_because(
+                &format!("expected length within [{}, {}] but got {}", low, high, actual_length)
+            )
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if the asserted iterator yields exactly the expected number of items.
+///
+/// Counting the items requires consuming the iterator.
+/// As a `Matcher` only borrows the asserted value, the iterator is cloned and the clone is consumed,
+/// i.e., the asserted iterator itself is left untouched but any side effects of the pipeline happen during the check.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&(1..10).filter(|x| x % 3 == 0), yields_count(3));
+/// ```
+pub fn yields_count<'a,I:'a>(expected_count: usize) -> Box<dyn Matcher<'a,I> + 'a>
+where I: Iterator + Clone {
+    Box::new(move |iterator: &'a I| {
+        let builder = MatchResultBuilder::for_("yields_count");
+        let actual_count = iterator.clone().count();
+        if actual_count != expected_count {
+            builder.failed_because(
+                &format!("expected {} items but the iterator yielded {}", expected_count, actual_count)
+            )
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if the asserted collection has the same length as the `other` collection.
+///
+/// The length of `other` is captured when the matcher is created.
+///
+/// # Examples
+/// ```rust,ignore
+/// let input = vec![1,2,3];
+/// assert_that!(&input.iter().map(|x| x * 2).collect::<Vec<_>>(), same_length_as(&input));
+/// ```
+pub fn same_length_as<'a,U,I:'a + ?Sized>(other: &[U]) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator + 'a {
+    let expected_length = other.len();
+    Box::new(move |elements: &'a I| {
+        let builder = MatchResultBuilder::for_("same_length_as");
+        let actual_length = elements.into_iter().count();
+        if actual_length != expected_length {
+            builder.failed_because(
+                &format!("lengths differ\n\tExpected length: {}\n\tActual length: {}", expected_length, actual_length)
+            )
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if the length of the asserted collection satisfies the given `Matcher`.
+///
+/// The length is determined by iterating over the collection.
+///
+/// In contrast to the other nested matchers, e.g., `all_elements`, this matcher does not accept a boxed matcher
+/// like `greater_than(2)`, i.e., a `Box<dyn Matcher<'a,usize>>`.
+/// Such a matcher requires the length to be borrowed for the lifetime `'a` of the asserted collection,
+/// but the length is computed by the check and dropped at its end.
+/// The nested matcher has to accept a length borrowed for any lifetime, i.e., implement `for<'b> Matcher<'b,usize>`.
+/// Usually this is a closure which creates a boxed matcher on each check and applies it.
+/// Use `has_length` or `has_length_between` instead if the bounds of the length are known upfront.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&vec![1,2,3], has_length_matching(|length: &usize| greater_than(2).check(length)));
+/// ```
+pub fn has_length_matching<'a,I:'a + ?Sized,M>(matcher: M) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator + 'a,
+      M: for<'b> Matcher<'b,usize> + 'a {
+    Box::new(move |elements: &'a I| {
+        let actual_length = elements.into_iter().count();
+        match matcher.check(&actual_length) {
+            MatchResult::Matched { name } =>
+                MatchResultBuilder::for_(&format!("has_length_matching({})", name)).matched(),
+            failed => MatchResultBuilder::for_(&format!("has_length_matching({})", failed.name()))
+                                         .failed_because_of(&format!("length {}", actual_length), failed)
+        }
+    })
+}
+
+/// A collection-like type which can be asserted to be empty by [is_empty].
+pub trait Emptiness {
+    /// Returns the number of elements (or bytes for strings) in the collection.
+    fn length(&self) -> usize;
+}
+
+impl<T> Emptiness for Vec<T> {
+    fn length(&self) -> usize { self.len() }
+}
+
+impl Emptiness for String {
+    fn length(&self) -> usize { self.len() }
+}
+
+impl Emptiness for &str {
+    fn length(&self) -> usize { self.len() }
+}
+
+impl<K,V,S> Emptiness for std::collections::HashMap<K,V,S>
+where K: Eq + std::hash::Hash,
+      S: std::hash::BuildHasher {
+    fn length(&self) -> usize { self.len() }
+}
+
+/// Matches if the asserted collection or string is empty.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&Vec::<i32>::new(), is_empty());
+/// assert_that!(&"", is_empty());
+/// ```
+pub fn is_empty<'a,T>() -> Box<dyn Matcher<'a,T> + 'a>
+where T: Emptiness + 'a {
+    Box::new(|actual: &T| {
+        let builder = MatchResultBuilder::for_("is_empty");
+        let length = actual.length();
+        if length != 0 {
+            builder.failed_because(&format!("expected to be empty but has length {}", length))
+        } else { builder.matched() }
+    })
+}
+
 /// Matches if the elements in the asserted collection are sorted weakly monotone according to the given `predicate` in the expected order.
 ///
 /// The `predicate` is applied to all consecutive pairs of elements and returns the `Ordering` of the pair.
 /// The ordering is allowed to be weakly monotone, i.e., equal elements are allowed to follow each other.
 /// An empty collection is assumed to be always sorted.
-pub fn sorted_by<'a,T,I,P>(predicate: P, expected_ordering: std::cmp::Ordering) -> Box<Fn(&'a I) -> MatchResult>
+pub fn sorted_by<'a,T,I,P>(predicate: P, expected_ordering: std::cmp::Ordering) -> Box<dyn Fn(&'a I) -> MatchResult>
 where &'a I: IntoIterator<Item=&'a T> + 'a,
       T: Ord + Debug + 'a,
       P: Fn(&'a T,&'a T) -> std::cmp::Ordering + 'static {
@@ -194,7 +642,7 @@ where &'a I: IntoIterator<Item=&'a T> + 'a,
         let mut prev = maybe_prev.unwrap();
 
         for cur in iter {
-            let ordering = predicate(&prev, &cur);
+            let ordering = predicate(prev, cur);
             if ordering != std::cmp::Ordering::Equal
                       && expected_ordering != ordering  {
                 return builder.failed_because(
@@ -213,7 +661,7 @@ where &'a I: IntoIterator<Item=&'a T> + 'a,
 /// The `predicate` is applied to all consecutive pairs of elements and returns the `Ordering` of the pair.
 /// The ordering is allowed to be weakly monotone, i.e., equal elements are allowed to follow each other.
 /// An empty collection is assumed to be always sorted.
-pub fn sorted_strictly_by<'a,T,I,P>(predicate: P, expected_ordering: std::cmp::Ordering) -> Box<Fn(&'a I) -> MatchResult>
+pub fn sorted_strictly_by<'a,T,I,P>(predicate: P, expected_ordering: std::cmp::Ordering) -> Box<dyn Fn(&'a I) -> MatchResult>
 where &'a I: IntoIterator<Item=&'a T> + 'a,
       T: Ord + Debug + 'a,
       P: Fn(&'a T,&'a T) -> std::cmp::Ordering + 'static {
@@ -226,7 +674,7 @@ where &'a I: IntoIterator<Item=&'a T> + 'a,
         let mut prev = maybe_prev.unwrap();
 
         for cur in iter {
-            let ordering = predicate(&prev, &cur);
+            let ordering = predicate(prev, cur);
             if expected_ordering != ordering  {
                 return builder.failed_because(
                     &format!("ordering is not strictly monotone: predicate({:?}, {:?}) != {:?}", prev, cur, expected_ordering)
@@ -244,7 +692,7 @@ where &'a I: IntoIterator<Item=&'a T> + 'a,
 /// The first `Ordering` different to `Ordering::Equal` defines the expected order of the collection.
 /// The ordering is allowed to be weakly monotone, i.e., equal elements are allowed to follow each other.
 /// An empty collection is assumed to be always sorted.
-pub fn sorted_by_in_any_order<'a,T,I,P>(predicate: P) -> Box<Fn(&'a I) -> MatchResult>
+pub fn sorted_by_in_any_order<'a,T,I,P>(predicate: P) -> Box<dyn Fn(&'a I) -> MatchResult>
 where &'a I: IntoIterator<Item=&'a T> + 'a,
       T: Ord + Debug + 'a,
       P: Fn(&'a T,&'a T) -> std::cmp::Ordering + 'static {
@@ -259,8 +707,8 @@ where &'a I: IntoIterator<Item=&'a T> + 'a,
         let mut prev = maybe_prev.unwrap();
 
         for cur in iter {
-            let ordering = predicate(&prev, &cur);
-            if expected_ordering == None && ordering != std::cmp::Ordering::Equal {
+            let ordering = predicate(prev, cur);
+            if expected_ordering.is_none() && ordering != std::cmp::Ordering::Equal {
                 expected_ordering = Some(ordering);
             } else if ordering != std::cmp::Ordering::Equal
                       && expected_ordering.unwrap() != ordering  {
@@ -281,7 +729,7 @@ where &'a I: IntoIterator<Item=&'a T> + 'a,
 /// The first `Ordering` different to `Ordering::Equal` defines the expected order of the collection.
 /// The ordering is allowed to be weakly monotone, i.e., equal elements are allowed to follow each other.
 /// An empty collection is assumed to be always sorted.
-pub fn sorted_strictly_by_in_any_order<'a,T,I,P>(predicate: P) -> Box<Fn(&'a I) -> MatchResult>
+pub fn sorted_strictly_by_in_any_order<'a,T,I,P>(predicate: P) -> Box<dyn Fn(&'a I) -> MatchResult>
 where &'a I: IntoIterator<Item=&'a T> + 'a,
       T: Ord + Debug + 'a,
       P: Fn(&'a T,&'a T) -> std::cmp::Ordering + 'static {
@@ -296,20 +744,20 @@ where &'a I: IntoIterator<Item=&'a T> + 'a,
         let mut prev = maybe_prev.unwrap();
 
         for cur in iter {
-            let ordering = predicate(&prev, &cur);
+            let ordering = predicate(prev, cur);
             if ordering == std::cmp::Ordering::Equal {
                 return builder.failed_because(
                     &format!("ordering is not strictly monotone: predicate({:?}, {:?}) = {:?}",
                              prev, cur, ordering)
                 );
             }
-            if expected_ordering == None {
-                expected_ordering = Some(ordering);
-            } else if expected_ordering.unwrap() != ordering  {
-                return builder.failed_because(
+            match expected_ordering {
+                None => expected_ordering = Some(ordering),
+                Some(expected) if expected != ordering => return builder.failed_because(
                     &format!("ordering is not strictly monotone: predicate({:?}, {:?}) != {:?}",
-                             prev, cur, expected_ordering.unwrap())
-                );
+                             prev, cur, expected)
+                ),
+                _ => { }
             }
             prev = cur;
         }
@@ -320,7 +768,7 @@ where &'a I: IntoIterator<Item=&'a T> + 'a,
 /// Matches if the asserted collection is sorted weakly ascending.
 ///
 /// An empty collection is assumed to be always sorted.
-pub fn sorted_ascending<'a,T,I>() -> Box<Fn(&'a I) -> MatchResult>
+pub fn sorted_ascending<'a,T,I>() -> Box<dyn Fn(&'a I) -> MatchResult>
 where &'a I: IntoIterator<Item=&'a T> + 'a,
       T: Ord + Debug + 'a {
     sorted_by(|a: &T, b: &T| a.cmp(b), std::cmp::Ordering::Less)
@@ -329,7 +777,7 @@ where &'a I: IntoIterator<Item=&'a T> + 'a,
 /// Matches if the asserted collection is sorted strictly ascending.
 ///
 /// An empty collection is assumed to be always sorted.
-pub fn sorted_strictly_ascending<'a,T,I>() -> Box<Fn(&'a I) -> MatchResult>
+pub fn sorted_strictly_ascending<'a,T,I>() -> Box<dyn Fn(&'a I) -> MatchResult>
 where &'a I: IntoIterator<Item=&'a T> + 'a,
       T: Ord + Debug + 'a {
     sorted_strictly_by(|a: &T, b: &T| a.cmp(b), std::cmp::Ordering::Less)
@@ -338,7 +786,7 @@ where &'a I: IntoIterator<Item=&'a T> + 'a,
 /// Matches if the asserted collection is sorted weakly descending.
 ///
 /// An empty collection is assumed to be always sorted.
-pub fn sorted_descending<'a,T,I>() -> Box<Fn(&'a I) -> MatchResult>
+pub fn sorted_descending<'a,T,I>() -> Box<dyn Fn(&'a I) -> MatchResult>
 where &'a I: IntoIterator<Item=&'a T> + 'a,
       T: Ord + Debug + 'a {
     sorted_by(|a: &T, b: &T| a.cmp(b), std::cmp::Ordering::Greater)
@@ -347,16 +795,128 @@ where &'a I: IntoIterator<Item=&'a T> + 'a,
 /// Matches if the asserted collection is sorted strictly descending.
 ///
 /// An empty collection is assumed to be always sorted.
-pub fn sorted_strictly_descending<'a,T,I>() -> Box<Fn(&'a I) -> MatchResult>
+pub fn sorted_strictly_descending<'a,T,I>() -> Box<dyn Fn(&'a I) -> MatchResult>
 where &'a I: IntoIterator<Item=&'a T> + 'a,
       T: Ord + Debug + 'a {
     sorted_strictly_by(|a: &T, b: &T| a.cmp(b), std::cmp::Ordering::Greater)
 }
 
+/// Matches if the elements in the asserted collection are in order according to the given `predicate`.
+///
+/// The `predicate` is applied to all consecutive pairs of elements and returns `true` if the pair is in order.
+/// On failure the index of the first pair violating the order is reported.
+/// An empty collection is assumed to be always sorted.
+pub fn is_sorted_by<'a,T:'a,I:'a + ?Sized,P>(predicate: P) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator<Item=&'a T>,
+      T: Debug,
+      P: Fn(&T,&T) -> bool + 'a {
+    pairwise_in_order("is_sorted_by", predicate)
+}
+
+/// Matches if the elements in the asserted collection are sorted in nondecreasing order.
+///
+/// In contrast to `sorted_ascending` only `PartialOrd` is required, e.g., to assert collections of floating point values.
+/// Incomparable elements, e.g., `NaN`, are considered to be out of order.
+/// An empty collection is assumed to be always sorted.
+pub fn is_sorted<'a,T:'a,I:'a + ?Sized>() -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator<Item=&'a T>,
+      T: PartialOrd + Debug {
+    pairwise_in_order("is_sorted", |a: &T, b: &T| a <= b)
+}
+
+/// Matches if the keys derived by `key_fn` from the elements in the asserted collection are sorted in nondecreasing order.
+///
+/// Incomparable keys, e.g., `NaN`, are considered to be out of order.
+/// A failure reports the indices and keys of the first pair of elements which is out of order.
+/// An empty collection is assumed to be always sorted.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&vec!["a", "bb", "ccc"], is_sorted_by_key(|s: &&str| s.len()));
+/// ```
+pub fn is_sorted_by_key<'a,T:'a,I:'a + ?Sized,K,F>(key_fn: F) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator<Item=&'a T>,
+      K: PartialOrd + Debug,
+      F: Fn(&T) -> K + 'a {
+    Box::new(move |elements: &'a I| {
+        let builder = MatchResultBuilder::for_("is_sorted_by_key");
+        let mut keys = elements.into_iter().map(&key_fn);
+        let mut prev = match keys.next() {
+            Some(first) => first,
+            None => return builder.matched()
+        };
+
+        for (index, cur) in keys.enumerate() {
+            let in_order = prev <= cur;
+            if !in_order {
+                return builder.failed_because(
+                    &format!("keys of elements at index {} and {} are out of order: {:?}, {:?}",
+                             index, index + 1, prev, cur)
+                );
+            }
+            prev = cur;
+        }
+        builder.matched()
+    })
+}
+
+/// Matches if the keys derived by `key_fn` from the elements in the asserted collection are pairwise distinct.
+///
+/// Only `PartialEq` is required of the keys, so each key is compared with all preceding keys.
+/// A failure reports the first duplicated key and the indices of both elements.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&users, has_unique_keys(|u: &User| u.id));
+/// ```
+pub fn has_unique_keys<'a,T:'a,I:'a + ?Sized,K,F>(key_fn: F) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator<Item=&'a T>,
+      K: PartialEq + Debug,
+      F: Fn(&T) -> K + 'a {
+    Box::new(move |elements: &'a I| {
+        let builder = MatchResultBuilder::for_("has_unique_keys");
+        let mut keys: Vec<K> = Vec::new();
+        for (index, key) in elements.into_iter().map(&key_fn).enumerate() {
+            if let Some(first) = keys.iter().position(|seen| *seen == key) {
+                return builder.failed_because(
+                    &format!("elements at index {} and {} have the same key {:?}", first, index, key)
+                );
+            }
+            keys.push(key);
+        }
+        builder.matched()
+    })
+}
+
+fn pairwise_in_order<'a,T:'a,I:'a + ?Sized,P>(name: &'static str, predicate: P) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator<Item=&'a T>,
+      T: Debug,
+      P: Fn(&T,&T) -> bool + 'a {
+    Box::new(move |elements: &'a I| {
+        let builder = MatchResultBuilder::for_(name);
+        let mut iter = elements.into_iter();
+        let mut prev = match iter.next() {
+            Some(first) => first,
+            None => return builder.matched()
+        };
+
+        for (index, cur) in iter.enumerate() {
+            if !predicate(prev, cur) {
+                return builder.failed_because(
+                    &format!("elements at index {} and {} are out of order: {:?}, {:?}",
+                             index, index + 1, prev, cur)
+                );
+            }
+            prev = cur;
+        }
+        builder.matched()
+    })
+}
+
 /// Matches if all elements in the asserted collection satisfy the given `predicate`.
 ///
 /// An empty collection always satisfies this matcher as all (=no) element satisfies the predicate.
-pub fn all_elements_satisfy<'a,T,I,P>(predicate: P) -> Box<Fn(&'a I) -> MatchResult>
+pub fn all_elements_satisfy<'a,T,I,P>(predicate: P) -> Box<dyn Fn(&'a I) -> MatchResult>
 where T: Debug + 'a,
       &'a I: IntoIterator<Item=&'a T> + 'a,
       P: Fn(&'a T) -> bool + 'static {
@@ -373,16 +933,132 @@ where T: Debug + 'a,
     })
 }
 
+/// Matches if all elements in the asserted collection satisfy the given `Matcher`.
+///
+/// The matcher fails on the first element which does not satisfy the nested matcher
+/// and reports the index of the element together with the nested failure.
+/// An empty collection always satisfies this matcher as all (=no) element satisfies the nested matcher.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&vec![1,2,3], all_elements(greater_than_or_equal(0)));
+/// ```
+pub fn all_elements<'a,T:'a,I:'a + ?Sized>(matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator<Item=&'a T> + 'a {
+    Box::new(move |elements: &'a I| {
+        for (idx, element) in elements.into_iter().enumerate() {
+            let result = matcher.check(element);
+            if result.is_failed() {
+                return MatchResultBuilder::for_(&format!("all_elements({})", result.name()))
+                           .failed_because_of(&format!("element at index {}", idx), result);
+            }
+        }
+        MatchResultBuilder::for_("all_elements").matched()
+    })
+}
+
+/// Matches if at least one element in the asserted collection satisfies the given `Matcher`.
+///
+/// An empty collection never satisfies this matcher as no element satisfies the nested matcher.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&vec!["info: started", "error: failed"], any_element(starts_with("error")));
+/// ```
+pub fn any_element<'a,T:'a,I:'a + ?Sized>(matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator<Item=&'a T> + 'a {
+    Box::new(move |elements: &'a I| {
+        let mut length = 0;
+        let mut nested_name = None;
+        for element in elements.into_iter() {
+            length += 1;
+            match matcher.check(element) {
+                MatchResult::Matched { .. } => return MatchResultBuilder::for_("any_element").matched(),
+                MatchResult::Failed { name, .. } => nested_name = Some(name)
+            }
+        }
+        match nested_name {
+            Some(name) => MatchResultBuilder::for_(&format!("any_element({})", name))
+                              .failed_because(&format!("none of the {} elements matches {}", length, name)),
+            None => MatchResultBuilder::for_("any_element")
+                        .failed_because("the collection is empty")
+        }
+    })
+}
+
+/// Matches if exactly `count` elements in the asserted collection satisfy the given `Matcher`.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&vec![Ok(1), Err("a"), Err("b")], count_matching(2, is_err()));
+/// ```
+pub fn count_matching<'a,T:'a,I:'a + ?Sized>(count: usize, matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator<Item=&'a T> + 'a {
+    Box::new(move |elements: &'a I| {
+        let (name, indices) = matching_indices(&*matcher, elements);
+        let builder = MatchResultBuilder::for_(&format!("count_matching({})", name));
+        if indices.len() == count {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("expected {} matching elements but got {}\n\tMatching indices: {:?}",
+                                            count, indices.len(), indices))
+        }
+    })
+}
+
+/// Matches if at least `count` elements in the asserted collection satisfy the given `Matcher`.
+pub fn at_least_matching<'a,T:'a,I:'a + ?Sized>(count: usize, matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator<Item=&'a T> + 'a {
+    Box::new(move |elements: &'a I| {
+        let (name, indices) = matching_indices(&*matcher, elements);
+        let builder = MatchResultBuilder::for_(&format!("at_least_matching({})", name));
+        if indices.len() >= count {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("expected at least {} elements matching {} but got {}",
+                                            count, name, indices.len()))
+        }
+    })
+}
+
+/// Matches if at most `count` elements in the asserted collection satisfy the given `Matcher`.
+pub fn at_most_matching<'a,T:'a,I:'a + ?Sized>(count: usize, matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,I> + 'a>
+where &'a I: IntoIterator<Item=&'a T> + 'a {
+    Box::new(move |elements: &'a I| {
+        let (name, indices) = matching_indices(&*matcher, elements);
+        let builder = MatchResultBuilder::for_(&format!("at_most_matching({})", name));
+        if indices.len() <= count {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("expected at most {} elements matching {} but got {}",
+                                            count, name, indices.len()))
+        }
+    })
+}
+
+fn matching_indices<'a,T:'a,I:'a + ?Sized>(matcher: &dyn Matcher<'a,T>, elements: &'a I) -> (String, Vec<usize>)
+where &'a I: IntoIterator<Item=&'a T> {
+    let mut matcher_name = String::from("_unknown_");
+    let mut indices = Vec::new();
+    for (idx, element) in elements.into_iter().enumerate() {
+        match matcher.check(element) {
+            MatchResult::Matched { name } => { matcher_name = name; indices.push(idx); },
+            MatchResult::Failed { name, .. } => matcher_name = name
+        }
+    }
+    (matcher_name, indices)
+}
+
 /// Matches if at least one element in the asserted collection satisfy the given `predicate`.
 ///
 /// An empty collection never satisfies this matcher as no element satisfies the predicate.
-pub fn some_elements_satisfy<'a,T,I,P>(predicate: P) -> Box<Fn(&'a I) -> MatchResult>
+pub fn some_elements_satisfy<'a,T,I,P>(predicate: P) -> Box<dyn Fn(&'a I) -> MatchResult>
 where T: Debug + 'a,
       &'a I: IntoIterator<Item=&'a T> + 'a,
       P: Fn(&T) -> bool + 'static {
     Box::new(move |elements: &'a I| {
         let builder = MatchResultBuilder::for_("some_elements_satisfy");
-        if !elements.into_iter().any(|ref e| predicate(e)) {
+        if !elements.into_iter().any(&predicate) {
             builder.failed_because("no elements satisfy the predicate")
         } else {
             builder.matched()
@@ -409,12 +1085,12 @@ pub struct HasEntry<K,V> {
 ///
 /// The alternative would be to use the Index trait though experiments showed
 /// that this would not be composable with `all_of!` or `any_of!`.
-pub fn has_entry<'a,K:'a,V:'a,M:'a>(key: K, value: V) -> Box<Matcher<'a,M> + 'a>
+pub fn has_entry<'a,K:'a,V:'a,M:'a>(key: K, value: V) -> Box<dyn Matcher<'a,M> + 'a>
 where &'a M: IntoIterator<Item=(&'a K,&'a V)> + 'a,
       HasEntry<K,V>: Matcher<'a,M> {
     Box::new(HasEntry {
-        key: key,
-        value: value
+        key,
+        value
     })
 }
 
@@ -439,14 +1115,83 @@ where V: PartialEq + Debug + 'a,
             }
         }
 
-        builder.failed_because(&format!(
-            "Entry ({:?}, {:?}) not found.\n\tEntries with same key: {:?}\n\tEntries with same value: {:?}",
-            &self.key, &self.value,
-            same_keys, same_values
-        ))
+        if same_keys.is_empty() {
+            builder.failed_because(&format!(
+                "No entry with key {:?} found.\n\tEntries with same value: {:?}",
+                &self.key, same_values
+            ))
+        } else {
+            builder.failed_because(&format!(
+                "Entry with key {:?} has a different value.\n\tExpected: {:?}\n\tGot: {}",
+                &self.key, &self.value,
+                if same_keys.len() == 1 { format!("{:?}", same_keys[0]) } else { format!("{:?}", same_keys) }
+            ))
+        }
     }
 }
 
+/// Matches if the map-like collection contains all of the given key/value pairs.
+///
+/// The `Matcher` tests for this by converting the map-like data structure
+/// into a key/value pair iterator.
+/// All pairs are checked, so the failure lists every missing key and every key with a different value.
+pub fn has_entries<'a,K:'a,V:'a,M:'a>(pairs: Vec<(K,V)>) -> Box<dyn Matcher<'a,M> + 'a>
+where K: PartialEq + Debug,
+      V: PartialEq + Debug,
+      &'a M: IntoIterator<Item=(&'a K,&'a V)> + 'a {
+    Box::new(move |map: &'a M| {
+        let builder = MatchResultBuilder::for_("has_entries");
+        let entries: Vec<_> = map.into_iter().collect();
+        let mut missing_keys = Vec::new();
+        let mut different_values = Vec::new();
+        for (key, value) in &pairs {
+            match entries.iter().find(|&&(entry_key, _)| entry_key == key) {
+                None => missing_keys.push(key),
+                Some(&(_, entry_value)) if entry_value != value =>
+                    different_values.push(format!("\n\t\t{:?}: expected {:?} but got {:?}", key, value, entry_value)),
+                Some(_) => { }
+            }
+        }
+
+        if missing_keys.is_empty() && different_values.is_empty() {
+            return builder.matched();
+        }
+        let mut reason = String::new();
+        if !missing_keys.is_empty() {
+            reason.push_str(&format!("\n\tMissing keys: {:?}", missing_keys));
+        }
+        if !different_values.is_empty() {
+            reason.push_str(&format!("\n\tDifferent values:{}", different_values.concat()));
+        }
+        builder.failed_because(&format!("not all entries are present{}", reason))
+    })
+}
+
+/// Matches if the map-like collection contains an entry whose key and value satisfy the given matchers.
+///
+/// The `Matcher` tests for this by converting the map-like data structure
+/// into a key/value pair iterator.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&map, has_entry_matching(starts_with("user_"), greater_than(0)));
+/// ```
+pub fn has_entry_matching<'a,K:'a,V:'a,M:'a>(key_matcher: Box<dyn Matcher<'a,K> + 'a>,
+                                             value_matcher: Box<dyn Matcher<'a,V> + 'a>) -> Box<dyn Matcher<'a,M> + 'a>
+where &'a M: IntoIterator<Item=(&'a K,&'a V)> + 'a {
+    Box::new(move |map: &'a M| {
+        let builder = MatchResultBuilder::for_("has_entry_matching");
+        let mut examined = 0;
+        for (key, value) in map.into_iter() {
+            examined += 1;
+            if let (MatchResult::Matched { .. }, MatchResult::Matched { .. }) = (key_matcher.check(key), value_matcher.check(value)) {
+                return builder.matched();
+            }
+        }
+        builder.failed_because(&format!("none of the {} examined entries matches both the key and the value matcher", examined))
+    })
+}
+
 /// Matches if the map-like collection contains the given key.
 ///
 /// The `Matcher` tests for this by converting the map-like data structure
@@ -465,28 +1210,31 @@ pub struct HasKey<K> {
 ///
 /// The alternative would be to use the Index trait though experiments showed
 /// that this would not be composable with `all_of!` or `any_of!`.
-pub fn has_key<'a,K:'a,V:'a,M:'a>(key: K) -> Box<Matcher<'a,M> + 'a>
+pub fn has_key<'a,K:'a,V:'a,M:'a>(key: K) -> Box<dyn Matcher<'a,M> + 'a>
 where &'a M: IntoIterator<Item=(&'a K,&'a V)> + 'a,
       HasKey<K>: Matcher<'a,M> {
     Box::new(HasKey {
-        key: key
+        key
     })
 }
 
 impl<'a,K,V,M> Matcher<'a,M> for HasKey<K>
-where V: PartialEq + Debug + 'a,
+where V: 'a,
       K: PartialEq + Debug + 'a,
       &'a M: IntoIterator<Item=(&'a K,&'a V)> + 'a {
 
     fn check(&self, map: &'a M) -> MatchResult {
         let builder = MatchResultBuilder::for_("has_key");
+        let mut present_keys = Vec::new();
         for (key, _) in map.into_iter() {
             if key == &self.key {
                 return builder.matched();
             }
+            present_keys.push(key);
         }
 
-        builder.failed_because(&format!("No entrywith key {:?} found", &self.key))
+        builder.failed_because(&format!("No entry with key {:?} found.\n\tPresent keys: {:?}",
+                                        &self.key, present_keys))
     }
 }
 
@@ -503,11 +1251,11 @@ pub struct HasValue<V> {
 ///
 /// The `Matcher` tests for this by converting the map-like data structure
 /// into a key/value pair iterator.
-pub fn has_value<'a,K:'a,V:'a,M:'a>(key: K) -> Box<Matcher<'a,M> + 'a>
+pub fn has_value<'a,K:'a,V:'a,M:'a>(key: K) -> Box<dyn Matcher<'a,M> + 'a>
 where &'a M: IntoIterator<Item=(&'a K,&'a V)> + 'a,
       HasKey<K>: Matcher<'a,M> {
     Box::new(HasKey {
-        key: key
+        key
     })
 }
 
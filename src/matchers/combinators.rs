@@ -16,37 +16,71 @@
 use super::super::*;
 
 /// Takes a list of matchers for the same type combines them conjunctively.
+///
+/// The name of a failing matcher is prefixed with its index in the list, e.g., `[1] less_than`.
+/// Use `labeled_all_of!` to prefix the names with custom labels.
 #[macro_export]
 macro_rules! all_of {
-    ( $matcher: expr ) => {
-        Box::new(All::of($matcher))
+    ( @and $all: expr, $index: expr; ) => {
+        $all
     };
-    ( $matcher: expr, $($matchers: expr),* ) => {
-        Box::new(All::of($matcher)$(.and($matchers))*)
+    ( @and $all: expr, $index: expr; $matcher: expr $(, $matchers: expr)* ) => {
+        all_of!(@and $all.and(galvanic_assert::matchers::labeled(&($index).to_string(), $matcher)), $index + 1; $($matchers),*)
     };
+    ( $matcher: expr $(, $matchers: expr)* ) => {
+        Box::new(all_of!(@and All::of(galvanic_assert::matchers::labeled("0", $matcher)), 1usize; $($matchers),*))
+    };
+}
+
+/// Takes a list of `(label, matcher)` pairs for the same type and combines the matchers conjunctively.
+///
+/// The name of a failing matcher is prefixed with its label, e.g., `[upper bound] less_than`.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&5, labeled_all_of!(("lower bound", gt(0)), ("upper bound", lt(10))));
+/// ```
+#[macro_export]
+macro_rules! labeled_all_of {
+    ( ($label: expr, $matcher: expr) $(, ($labels: expr, $matchers: expr))* $(,)* ) => {
+        Box::new(All::of(galvanic_assert::matchers::labeled($label, $matcher))$(.and(galvanic_assert::matchers::labeled($labels, $matchers)))*)
+    };
+}
+
+/// A matcher reporting the result of the passed matcher with its name prefixed by the given `label`.
+///
+/// The labels distinguish matchers of the same kind combined by `all_of!` and `any_of!`.
+pub fn labeled<'a,T:'a>(label: &str, matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,T> + 'a> {
+    let label = label.to_owned();
+    Box::new(move |actual: &'a T| {
+        match matcher.check(actual) {
+            MatchResult::Matched { name } => MatchResult::Matched { name: format!("[{}] {}", label, name) },
+            MatchResult::Failed { name, reason } => MatchResult::Failed { name: format!("[{}] {}", label, name), reason }
+        }
+    })
 }
 
 /// A `Matcher` struct which joins multiple `Matcher`s conjunctively.
 ///
 /// Use `of()` to create a new `Matcher` and `and()` to add further `Matcher`s.
 pub struct All<'a, T:'a> {
-    pub matcher: Box<Matcher<'a,T> + 'a>,
+    pub matcher: Box<dyn Matcher<'a,T> + 'a>,
     pub next: Option<Box<All<'a,T>>>
 }
 
 impl<'a,T:'a> All<'a, T> {
     /// Creates a new conjunctive `Matcher` starting with the given `Matcher`.
-    pub fn of(matcher: Box<Matcher<'a,T> + 'a>) -> All<'a,T> {
+    pub fn of(matcher: Box<dyn Matcher<'a,T> + 'a>) -> All<'a,T> {
         All {
-            matcher: matcher,
+            matcher,
             next: None
         }
     }
 
     /// Adds the given `Matcher` conjunctively.
-    pub fn and(self, matcher: Box<Matcher<'a,T> + 'a>) -> All<'a,T> {
+    pub fn and(self, matcher: Box<dyn Matcher<'a,T> + 'a>) -> All<'a,T> {
         All {
-            matcher: matcher,
+            matcher,
             next: Some(Box::new(self))
         }
     }
@@ -66,14 +100,49 @@ impl<'a,T:'a> Matcher<'a,T> for All<'a,T> {
     }
 }
 
+/// Extends boxed matchers with methods to combine them fluently.
+///
+/// The methods are an alternative to the `all_of!` and `any_of!` macros for matchers built programmatically.
+/// There is no precedence between the methods; a chain is grouped from left to right as written,
+/// i.e., `a.and_also(b).or_else_match(c)` is satisfied if `a` and `b` are satisfied or if `c` is satisfied.
+///
+/// # Examples
+/// ```rust,ignore
+/// let in_range = gt(0).and_also(lt(10));
+/// assert_that!(&5, in_range.or_else_match(eq(42)));
+/// ```
+pub trait MatcherExt<'a, T: 'a> {
+    /// Combines this matcher and the `other` matcher conjunctively.
+    fn and_also(self, other: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,T> + 'a>;
+
+    /// Combines this matcher and the `other` matcher disjunctively.
+    fn or_else_match(self, other: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,T> + 'a>;
+}
+
+impl<'a, T: 'a> MatcherExt<'a,T> for Box<dyn Matcher<'a,T> + 'a> {
+    fn and_also(self, other: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,T> + 'a> {
+        Box::new(All::of(self).and(other))
+    }
+
+    fn or_else_match(self, other: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,T> + 'a> {
+        Box::new(Any::of(self).or(other))
+    }
+}
+
 /// Takes a list of matchers for the same type combines them disjunctively.
+///
+/// If none of the matchers is satisfied the failure of the first one is reported,
+/// its name prefixed with its index in the list, e.g., `[0] less_than`.
 #[macro_export]
 macro_rules! any_of {
-    ( $matcher: expr ) => {
-        Box::new(Any::of($matcher))
+    ( @or $any: expr, $index: expr; ) => {
+        $any
     };
-    ( $matcher: expr, $($matchers: expr),* ) => {
-        Box::new(Any::of($matcher)$(.or($matchers))*)
+    ( @or $any: expr, $index: expr; $matcher: expr $(, $matchers: expr)* ) => {
+        any_of!(@or $any.or(galvanic_assert::matchers::labeled(&($index).to_string(), $matcher)), $index + 1; $($matchers),*)
+    };
+    ( $matcher: expr $(, $matchers: expr)* ) => {
+        Box::new(any_of!(@or Any::of(galvanic_assert::matchers::labeled("0", $matcher)), 1usize; $($matchers),*))
     };
 }
 
@@ -81,23 +150,23 @@ macro_rules! any_of {
 ///
 /// Use `of()` to create a new `Matcher` and `or()` to add further `Matcher`s.
 pub struct Any<'a, T:'a> {
-    pub matcher: Box<Matcher<'a,T> + 'a>,
+    pub matcher: Box<dyn Matcher<'a,T> + 'a>,
     pub next: Option<Box<Any<'a,T>>>
 }
 
 impl<'a,T:'a> Any<'a, T> {
     /// Creates a new conjunctive `Matcher` starting with the given `Matcher`.
-    pub fn of(matcher: Box<Matcher<'a,T> + 'a>) -> Any<'a,T> {
+    pub fn of(matcher: Box<dyn Matcher<'a,T> + 'a>) -> Any<'a,T> {
         Any {
-            matcher: matcher,
+            matcher,
             next: None
         }
     }
 
     /// Adds the given `Matcher` disjunctively.
-    pub fn or(self, matcher: Box<Matcher<'a,T> + 'a>) -> Any<'a,T> {
+    pub fn or(self, matcher: Box<dyn Matcher<'a,T> + 'a>) -> Any<'a,T> {
         Any {
-            matcher: matcher,
+            matcher,
             next: Some(Box::new(self))
         }
     }
@@ -114,3 +183,123 @@ impl<'a,T:'a> Matcher<'a,T> for Any<'a,T> {
         }
     }
 }
+
+/// Takes a list of matchers for the same type and combines them such that none of them is allowed to match.
+#[macro_export]
+macro_rules! none_of {
+    ( $matcher: expr ) => {
+        Box::new(NoneOf::of($matcher))
+    };
+    ( $matcher: expr, $($matchers: expr),* ) => {
+        Box::new(NoneOf::of($matcher)$(.nor($matchers))*)
+    };
+}
+
+/// A `Matcher` struct which joins multiple negated `Matcher`s conjunctively, i.e., none of them is allowed to match.
+///
+/// Use `of()` to create a new `Matcher` and `nor()` to add further `Matcher`s.
+pub struct NoneOf<'a, T:'a> {
+    pub matcher: Box<dyn Matcher<'a,T> + 'a>,
+    pub next: Option<Box<NoneOf<'a,T>>>
+}
+
+impl<'a,T:'a> NoneOf<'a, T> {
+    /// Creates a new negated conjunctive `Matcher` starting with the given `Matcher`.
+    pub fn of(matcher: Box<dyn Matcher<'a,T> + 'a>) -> NoneOf<'a,T> {
+        NoneOf {
+            matcher,
+            next: None
+        }
+    }
+
+    /// Adds the given `Matcher` negated conjunctively.
+    pub fn nor(self, matcher: Box<dyn Matcher<'a,T> + 'a>) -> NoneOf<'a,T> {
+        NoneOf {
+            matcher,
+            next: Some(Box::new(self))
+        }
+    }
+}
+
+impl<'a,T:'a> Matcher<'a,T> for NoneOf<'a,T> {
+    fn check(&self, actual: &'a T) -> MatchResult {
+        match self.matcher.check(actual) {
+            MatchResult::Matched { name } =>
+                MatchResultBuilder::for_("none_of")
+                                   .failed_because(&format!("{} is satisfied", name)),
+            MatchResult::Failed {..} => match self.next {
+                None => MatchResultBuilder::for_("none_of").matched(),
+                Some(ref next) => next.check(actual)
+            }
+        }
+    }
+}
+
+/// Takes a list of matchers for the same type and combines them such that exactly one of them has to match.
+#[macro_export]
+macro_rules! one_of {
+    ( $matcher: expr ) => {
+        Box::new(OneOf::of($matcher))
+    };
+    ( $matcher: expr, $($matchers: expr),* ) => {
+        Box::new(OneOf::of($matcher)$(.xor($matchers))*)
+    };
+}
+
+/// A `Matcher` struct which joins multiple `Matcher`s exclusively, i.e., exactly one of them has to match.
+///
+/// Use `of()` to create a new `Matcher` and `xor()` to add further `Matcher`s.
+pub struct OneOf<'a, T:'a> {
+    pub matcher: Box<dyn Matcher<'a,T> + 'a>,
+    pub next: Option<Box<OneOf<'a,T>>>
+}
+
+impl<'a,T:'a> OneOf<'a, T> {
+    /// Creates a new exclusive `Matcher` starting with the given `Matcher`.
+    pub fn of(matcher: Box<dyn Matcher<'a,T> + 'a>) -> OneOf<'a,T> {
+        OneOf {
+            matcher,
+            next: None
+        }
+    }
+
+    /// Adds the given `Matcher` exclusively.
+    pub fn xor(self, matcher: Box<dyn Matcher<'a,T> + 'a>) -> OneOf<'a,T> {
+        OneOf {
+            matcher,
+            next: Some(Box::new(self))
+        }
+    }
+}
+
+impl<'a,T:'a> Matcher<'a,T> for OneOf<'a,T> {
+    fn check(&self, actual: &'a T) -> MatchResult {
+        let builder = MatchResultBuilder::for_("one_of");
+        let mut matched = Vec::new();
+        let mut failed = Vec::new();
+
+        let mut current = Some(self);
+        while let Some(one_of) = current {
+            match one_of.matcher.check(actual) {
+                MatchResult::Matched { name } => matched.insert(0, name),
+                MatchResult::Failed { name, .. } => failed.insert(0, name)
+            }
+            current = one_of.next.as_deref();
+        }
+
+        match matched.len() {
+            1 => builder.matched(),
+            0 => builder.failed_because(
+                &format!("none of the matchers matched\n\tFailed: {:?}", failed)
+            ),
+            _ => builder.failed_because(
+                &format!("more than one matcher matched\n\tMatched: {:?}\n\tFailed: {:?}", matched, failed)
+            )
+        }
+    }
+}
+
+/// Matches if exactly one of the two given matchers matches.
+pub fn xor<'a,T:'a>(first: Box<dyn Matcher<'a,T> + 'a>, second: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,T> + 'a> {
+    Box::new(OneOf::of(first).xor(second))
+}
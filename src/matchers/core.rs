@@ -17,7 +17,11 @@
 //!
 //! The matchers in this module all operate on single values.
 
+use std::any::{type_name, Any};
+use std::cmp::Ordering;
 use std::fmt::Debug;
+use std::ops::Deref;
+use std::sync::Mutex;
 use super::super::*;
 
 macro_rules! matchresult_from_comparison {
@@ -28,16 +32,24 @@ macro_rules! matchresult_from_comparison {
         } else {
             builder.failed_comparison($actual, &$expected)
         }
+    }};
+    (  $actual: ident $comparison: tt $expected: ident, $name: expr, $label: expr ) => {{
+        let builder = MatchResultBuilder::for_($name);
+        if $actual $comparison &$expected {
+            builder.matched()
+        } else {
+            builder.failed_comparison_labeled($actual, "Got", &$expected, $label)
+        }
     }}
 }
 
 /// A matcher which always matches.
-pub fn assertion_always_succeeds<'a,T:'a>() -> Box<Matcher<'a,T> + 'a> {
+pub fn assertion_always_succeeds<'a,T:'a>() -> Box<dyn Matcher<'a,T> + 'a> {
     Box::new(|_s: &T| MatchResultBuilder::for_("succeeds_always").matched())
 }
 
 /// A matcher which never matches.
-pub fn assertion_always_fails<'a,T:'a>() -> Box<Matcher<'a,T> + 'a> {
+pub fn assertion_always_fails<'a,T:'a>() -> Box<dyn Matcher<'a,T> + 'a> {
     Box::new(|_s: &T| {
         MatchResultBuilder::for_("fails_always").failed_because("This matcher fails always")
     })
@@ -51,8 +63,69 @@ where M: Matcher<'a,T> {
     matcher
 }
 
+/// Matches if the asserted value satisfies the given `predicate`.
+///
+/// This is the preferred way to write one-off matchers,
+/// as no `MatchResult` has to be built and the `description` still yields a readable failure.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&4, satisfies("is even", |x: &i32| x % 2 == 0));
+/// ```
+pub fn satisfies<'a, T: 'a, P>(description: &str, predicate: P) -> Box<dyn Matcher<'a,T> + 'a>
+where P: Fn(&T) -> bool + 'a {
+    let description = description.to_owned();
+    Box::new(move |actual: &'a T| {
+        let builder = MatchResultBuilder::for_("satisfies");
+        if predicate(actual) {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("value did not satisfy: {}", description))
+        }
+    })
+}
+
+/// Negates the passed matcher without requiring it to be boxed.
+///
+/// Both boxed matchers and closures are accepted.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&1, not!(equal_to(5)));
+/// assert_that!(&1, not!(|x: &i32| MatchResultBuilder::for_("even").failed_because("odd")));
+/// ```
+#[macro_export]
+macro_rules! not {
+    ( $matcher: expr ) => {
+        galvanic_assert::matchers::not(galvanic_assert::matchers::IntoBoxedMatcher::into_boxed_matcher($matcher))
+    };
+}
+
+/// Converts a matcher into a boxed `Matcher` as expected by `not`.
+///
+/// Used by the `not!` macro, such that already boxed matchers are passed on as they are and only closures are boxed.
+pub trait IntoBoxedMatcher<'a, T: 'a> {
+    /// Returns the matcher as a boxed trait object.
+    fn into_boxed_matcher(self) -> Box<dyn Matcher<'a,T> + 'a>;
+}
+
+impl<'a, T: 'a> IntoBoxedMatcher<'a,T> for Box<dyn Matcher<'a,T> + 'a> {
+    fn into_boxed_matcher(self) -> Box<dyn Matcher<'a,T> + 'a> {
+        self
+    }
+}
+
+impl<'a, T: 'a, F> IntoBoxedMatcher<'a,T> for F
+where F: Fn(&'a T) -> MatchResult + 'a {
+    fn into_boxed_matcher(self) -> Box<dyn Matcher<'a,T> + 'a> {
+        Box::new(self)
+    }
+}
+
 /// A matcher negating the result of the passed matcher.
-pub fn not<'a, T: 'a>(matcher: Box<Matcher<'a,T> + 'a>) -> Box<Matcher<'a,T> + 'a> {
+///
+/// Use the `not!` macro to negate matchers which are not boxed.
+pub fn not<'a, T: 'a>(matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,T> + 'a> {
     Box::new(move |actual: &'a T| {
         match matcher.check(actual) {
             MatchResult::Matched { name } =>
@@ -64,78 +137,602 @@ pub fn not<'a, T: 'a>(matcher: Box<Matcher<'a,T> + 'a>) -> Box<Matcher<'a,T> + '
     })
 }
 
+/// A matcher reporting the result of the passed matcher under the given `name`.
+///
+/// Use it to give a built-in matcher a name describing its role in the assertion.
+pub fn named<'a, T: 'a>(name: &str, matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,T> + 'a> {
+    let name = name.to_owned();
+    Box::new(move |actual: &'a T| {
+        match matcher.check(actual) {
+            MatchResult::Matched { .. } => MatchResult::Matched { name: name.clone() },
+            MatchResult::Failed { reason, .. } => MatchResult::Failed { name: name.clone(), reason }
+        }
+    })
+}
+
+/// Applies the passed matcher only if the asserted value satisfies the `guard`.
+///
+/// If the `guard` does not hold the matcher is satisfied vacuously.
+///
+/// # Examples
+/// ```rust,ignore
+/// // negative values are exempt from the constraint
+/// assert_that!(&value, when(|x: &i32| *x >= 0, less_than(100)));
+/// ```
+pub fn when<'a, T: 'a, G>(guard: G, matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,T> + 'a>
+where G: Fn(&T) -> bool + 'a {
+    Box::new(move |actual: &'a T| {
+        if guard(actual) {
+            super::nested_result("when", matcher.check(actual))
+        } else {
+            MatchResultBuilder::for_("when").matched()
+        }
+    })
+}
+
+/// Matches if the target of the asserted smart pointer satisfies the passed matcher.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&Box::new(5), deref_to(equal_to(5)));
+/// assert_that!(&Rc::new("galvanic".to_owned()), deref_to(eq("galvanic".to_owned())));
+/// ```
+pub fn deref_to<'a, P: 'a, T: 'a + ?Sized>(matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,P> + 'a>
+where P: Deref<Target=T> {
+    Box::new(move |actual: &'a P| {
+        super::nested_result("deref_to", matcher.check(actual.deref()))
+    })
+}
+
+/// Matches if the value guarded by the asserted mutex satisfies the passed matcher.
+///
+/// Fails if the mutex is poisoned.
+/// The matcher is applied while the mutex is locked and the lock is released afterwards.
+///
+/// Unlike `deref_to` this matcher does not take a boxed matcher like `eq(4)`.
+/// The guarded value can only be borrowed while the lock is held and not for the lifetime of the asserted mutex,
+/// which a `Box<dyn Matcher<'a,T>>` would require.
+/// Hence the nested matcher must implement `for<'b> Matcher<'b,T>`, e.g.,
+/// a closure which creates and applies a boxed matcher on each check.
+///
+/// # Examples
+/// ```rust,ignore
+/// let counter = Arc::new(Mutex::new(0));
+/// // ... spawn and join threads incrementing the counter
+/// assert_that!(&*counter, locked_value(|value: &i32| eq(4).check(value)));
+/// ```
+pub fn locked_value<'a, T: 'a, M>(matcher: M) -> Box<dyn Matcher<'a,Mutex<T>> + 'a>
+where M: for<'b> Matcher<'b,T> + 'a {
+    Box::new(move |actual: &'a Mutex<T>| {
+        let guard = match actual.lock() {
+            Ok(guard) => guard,
+            Err(_) => return MatchResultBuilder::for_("locked_value")
+                                                .failed_because("the mutex is poisoned by a thread which panicked while holding the lock")
+        };
+        super::nested_result("locked_value", matcher.check(&*guard))
+    })
+}
+
+/// Matches if the value computed by `transform` from the asserted value satisfies the passed matcher.
+///
+/// The transformed value is owned by the check, so it cannot be handed to a boxed matcher like `lt(2)`
+/// which borrows the inspected value for the lifetime of the asserted value.
+/// In deviation from the other nested matchers the passed matcher therefore has to implement `for<'b> Matcher<'b,U>`,
+/// e.g., a closure which creates and applies a boxed matcher on each check.
+/// Use `field!` instead if the inspected value is a part of the asserted value, which accepts boxed matchers.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&"  galvanic  ", mapped(|s: &&str| s.trim().to_owned(),
+///                                      |trimmed: &String| eq("galvanic".to_owned()).check(trimmed)));
+/// ```
+pub fn mapped<'a, T: 'a, U, F, M>(transform: F, matcher: M) -> Box<dyn Matcher<'a,T> + 'a>
+where F: Fn(&T) -> U + 'a,
+      M: for<'b> Matcher<'b,U> + 'a {
+    Box::new(move |actual: &'a T| {
+        let transformed = transform(actual);
+        match matcher.check(&transformed) {
+            MatchResult::Matched { name } => MatchResultBuilder::for_(&format!("mapped({})", name)).matched(),
+            failed => MatchResultBuilder::for_(&format!("mapped({})", failed.name()))
+                                         .failed_because_of("the transformed value", failed)
+        }
+    })
+}
+
+/// Matches if the asserted type-erased value is of type `T` and satisfies the passed matcher.
+///
+/// # Examples
+/// ```rust,ignore
+/// let event: Box<dyn Any> = Box::new(42);
+/// assert_that!(&event, is_type::<i32>(eq(42)));
+/// ```
+pub fn is_type<'a, T: 'static>(matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,Box<dyn Any>> + 'a> {
+    Box::new(move |actual: &'a Box<dyn Any>| {
+        match actual.downcast_ref::<T>() {
+            Some(value) => super::nested_result("is_type", matcher.check(value)),
+            None => MatchResultBuilder::for_("is_type")
+                        .failed_because(&format!("value is not of type {}", type_name::<T>()))
+        }
+    })
+}
+
 /// Matches if the asserted value is equal to the expected value.
 ///
 /// This matcher should not be used when asserting floating point values.
 /// Use [close_to] instead.
-pub fn equal_to<'a, T>(expected: T) -> Box<Matcher<'a,T> + 'a>
+pub fn equal_to<'a, T>(expected: T) -> Box<dyn Matcher<'a,T> + 'a>
 where T: PartialEq + Debug + 'a {
     Box::new(move |actual: &T| matchresult_from_comparison!(actual == expected, "equal"))
 
 }
 /// Matches if the asserted value is equal to the expected value.
-pub fn eq<'a, T: PartialEq + Debug + 'a>(expected: T) -> Box<Matcher<'a,T> + 'a> { equal_to(expected) }
+pub fn eq<'a, T: PartialEq + Debug + 'a>(expected: T) -> Box<dyn Matcher<'a,T> + 'a> { equal_to(expected) }
+
+/// Converts a plain value into a `Matcher` checking for equality with the value.
+///
+/// `assert_that!` uses the conversion for literals given in place of a matcher, e.g., `assert_that!(&x, 5)`.
+/// Matchers themselves are not converted as a closure could then be a value as well as a matcher.
+pub trait ToMatcher<'a, T: 'a> {
+    /// Returns a matcher equivalent to `equal_to(self)`.
+    fn into_matcher(self) -> Box<dyn Matcher<'a,T> + 'a>;
+}
+
+impl<'a, T> ToMatcher<'a,T> for T
+where T: PartialEq + Debug + 'a {
+    fn into_matcher(self) -> Box<dyn Matcher<'a,T> + 'a> {
+        equal_to(self)
+    }
+}
 
 /// Matches if the asserted value is less than the expected value.
-pub fn less_than<'a, T>(expected: T) -> Box<Matcher<'a,T> + 'a>
+pub fn less_than<'a, T>(expected: T) -> Box<dyn Matcher<'a,T> + 'a>
 where T: PartialOrd + Debug + 'a {
-    Box::new(move |actual: &T| matchresult_from_comparison!(actual < expected, "less_than"))
+    Box::new(move |actual: &T| matchresult_from_comparison!(actual < expected, "less_than", "Bound"))
 }
 /// Matches if the asserted value is less than the expected value.
-pub fn lt<'a, T: PartialOrd + Debug + 'a>(expected: T) -> Box<Matcher<'a,T> + 'a> { less_than(expected) }
+pub fn lt<'a, T: PartialOrd + Debug + 'a>(expected: T) -> Box<dyn Matcher<'a,T> + 'a> { less_than(expected) }
 
 /// Matches if the asserted value is greater than the expected value.
-pub fn greater_than<'a, T>(expected: T) -> Box<Matcher<'a,T> + 'a>
+pub fn greater_than<'a, T>(expected: T) -> Box<dyn Matcher<'a,T> + 'a>
 where T: PartialOrd + Debug + 'a {
-    Box::new(move |actual: &T| matchresult_from_comparison!(actual > expected, "greater_than"))
+    Box::new(move |actual: &T| matchresult_from_comparison!(actual > expected, "greater_than", "Bound"))
 }
 /// Matches if the asserted value is greater than the expected value.
-pub fn gt<'a, T: PartialOrd + Debug + 'a>(expected: T) -> Box<Matcher<'a,T> + 'a> { greater_than(expected) }
+pub fn gt<'a, T: PartialOrd + Debug + 'a>(expected: T) -> Box<dyn Matcher<'a,T> + 'a> { greater_than(expected) }
 
 /// Matches if the asserted value is less than or equal to the expected value.
-pub fn less_than_or_equal<'a, T>(expected: T) -> Box<Matcher<'a,T> + 'a>
+pub fn less_than_or_equal<'a, T>(expected: T) -> Box<dyn Matcher<'a,T> + 'a>
 where T: PartialOrd + Debug + 'a {
-    Box::new(move |actual: &T| matchresult_from_comparison!(actual <= expected, "less_than_or_equal"))
+    Box::new(move |actual: &T| matchresult_from_comparison!(actual <= expected, "less_than_or_equal", "Bound"))
 }
 /// Matches if the asserted value is less than or equal to the expected value.
-pub fn leq<'a, T: PartialOrd + Debug + 'a>(expected: T) -> Box<Matcher<'a,T> + 'a> { less_than_or_equal(expected) }
+pub fn leq<'a, T: PartialOrd + Debug + 'a>(expected: T) -> Box<dyn Matcher<'a,T> + 'a> { less_than_or_equal(expected) }
 
 /// Matches if the asserted value is greater than or equal to the expected value.
-pub fn greater_than_or_equal<'a, T>(expected: T) -> Box<Matcher<'a,T> + 'a>
+pub fn greater_than_or_equal<'a, T>(expected: T) -> Box<dyn Matcher<'a,T> + 'a>
 where T: PartialOrd + Debug + 'a {
-    Box::new(move |actual: &T| matchresult_from_comparison!(actual >= expected, "greater_than_or_equal"))
+    Box::new(move |actual: &T| matchresult_from_comparison!(actual >= expected, "greater_than_or_equal", "Bound"))
 }
 /// Matches if the asserted value is greater than or equal to the expected value.
-pub fn geq<'a, T: PartialOrd + Debug + 'a>(expected: T) -> Box<Matcher<'a,T> + 'a> { greater_than_or_equal(expected) }
+pub fn geq<'a, T: PartialOrd + Debug + 'a>(expected: T) -> Box<dyn Matcher<'a,T> + 'a> { greater_than_or_equal(expected) }
+
+/// Matches if the asserted value is equal to the default value of its type.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&config.retries, is_default());
+/// ```
+pub fn is_default<'a, T>() -> Box<dyn Matcher<'a,T> + 'a>
+where T: Default + PartialEq + Debug + 'a {
+    let default = T::default();
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("is_default");
+        if *actual == default {
+            builder.matched()
+        } else {
+            builder.failed_comparison_labeled(actual, "Got", &default, "Default")
+        }
+    })
+}
+
+/// Matches if comparing the asserted value with the `other` value yields the `expected` ordering.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&"apple", compares_as(Ordering::Less, "banana"));
+/// ```
+pub fn compares_as<'a, T>(expected: Ordering, other: T) -> Box<dyn Matcher<'a,T> + 'a>
+where T: Ord + Debug + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("compares_as");
+        let ordering = actual.cmp(&other);
+        if ordering == expected {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} compares as {:?} to {:?} instead of {:?}", actual, ordering, other, expected))
+        }
+    })
+}
+
+/// A number type whose remainder of a division can be computed by [is_multiple_of].
+pub trait Remainder: Sized {
+    /// Returns the remainder of dividing `self` by the non-zero `divisor` without overflowing.
+    fn remainder(&self, divisor: &Self) -> Self;
+}
+
+macro_rules! impl_integer_remainder {
+    ( $($type: ty),* ) => {
+        $(impl Remainder for $type {
+            fn remainder(&self, divisor: &Self) -> Self { self.wrapping_rem(*divisor) }
+        })*
+    };
+}
+
+impl_integer_remainder!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl Remainder for f32 {
+    fn remainder(&self, divisor: &Self) -> Self { *self % *divisor }
+}
+
+impl Remainder for f64 {
+    fn remainder(&self, divisor: &Self) -> Self { *self % *divisor }
+}
+
+/// Matches if the asserted number is an integer multiple of the `divisor`.
+///
+/// The `Default` value of the number's type is taken as zero.
+/// Panics if the `divisor` is zero.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&std::mem::size_of::<Header>(), is_multiple_of(8));
+/// ```
+pub fn is_multiple_of<'a, T>(divisor: T) -> Box<dyn Matcher<'a,T> + 'a>
+where T: Remainder + PartialEq + Default + Debug + 'a {
+    if divisor == T::default() {
+        panic!("is_multiple_of requires a non-zero divisor");
+    }
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("is_multiple_of");
+        let remainder = actual.remainder(&divisor);
+        if remainder == T::default() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not a multiple of {:?}, the remainder is {:?}", actual, divisor, remainder))
+        }
+    })
+}
+
+/// Matches if the asserted number is greater than zero.
+///
+/// The `Default` value of the number's type is taken as zero.
+pub fn is_positive<'a, T>() -> Box<dyn Matcher<'a,T> + 'a>
+where T: PartialOrd + Default + Debug + 'a {
+    Box::new(|actual: &T| {
+        let builder = MatchResultBuilder::for_("is_positive");
+        if *actual > T::default() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not positive", actual))
+        }
+    })
+}
+
+/// Matches if the asserted number is less than zero.
+///
+/// The `Default` value of the number's type is taken as zero.
+pub fn is_negative<'a, T>() -> Box<dyn Matcher<'a,T> + 'a>
+where T: PartialOrd + Default + Debug + 'a {
+    Box::new(|actual: &T| {
+        let builder = MatchResultBuilder::for_("is_negative");
+        if *actual < T::default() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not negative", actual))
+        }
+    })
+}
+
+/// Matches if the asserted number is equal to zero.
+///
+/// The `Default` value of the number's type is taken as zero, so `-0.0` is zero as well.
+pub fn is_zero<'a, T>() -> Box<dyn Matcher<'a,T> + 'a>
+where T: PartialEq + Default + Debug + 'a {
+    Box::new(|actual: &T| {
+        let builder = MatchResultBuilder::for_("is_zero");
+        if *actual == T::default() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not zero", actual))
+        }
+    })
+}
+
+/// Matches if the asserted boolean is `true`.
+pub fn is_true<'a>() -> Box<dyn Matcher<'a,bool> + 'a> {
+    Box::new(|actual: &bool| {
+        let builder = MatchResultBuilder::for_("is_true");
+        if *actual { builder.matched() } else { builder.failed_comparison(actual, &true) }
+    })
+}
+
+/// Matches if the asserted boolean is `false`.
+pub fn is_false<'a>() -> Box<dyn Matcher<'a,bool> + 'a> {
+    Box::new(|actual: &bool| {
+        let builder = MatchResultBuilder::for_("is_false");
+        if !*actual { builder.matched() } else { builder.failed_comparison(actual, &false) }
+    })
+}
+
+/// Matches if the asserted value is equal to any of the `allowed` values.
+pub fn is_one_of<'a, T>(allowed: Vec<T>) -> Box<dyn Matcher<'a,T> + 'a>
+where T: PartialEq + Debug + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("is_one_of");
+        if allowed.contains(actual) {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not one of {:?}", actual, allowed))
+        }
+    })
+}
+
+/// Matches if the asserted value lies within the inclusive range from `low` to `high`.
+///
+/// Use [between_exclusive] if the endpoints should not be part of the range.
+pub fn between<'a, T>(low: T, high: T) -> Box<dyn Matcher<'a,T> + 'a>
+where T: PartialOrd + Debug + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("between");
+        if &low <= actual && actual <= &high {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not within [{:?}, {:?}]", actual, low, high))
+        }
+    })
+}
+
+/// Matches if the asserted value lies within the exclusive range from `low` to `high`.
+pub fn between_exclusive<'a, T>(low: T, high: T) -> Box<dyn Matcher<'a,T> + 'a>
+where T: PartialOrd + Debug + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("between_exclusive");
+        if &low < actual && actual < &high {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not within ({:?}, {:?})", actual, low, high))
+        }
+    })
+}
 
 /// Matches if the asserted value is in an epsilon range around the expected value.
 ///
-/// If floating point values are compared for equality this matcher should be used instead of [equal_to]
-pub fn close_to<'a, T>(expected: T, eps: T) -> Box<Matcher<'a,T> + 'a>
-where T: Copy + PartialOrd + std::ops::Add<Output=T> + std::ops::Sub<Output=T> + Debug + 'a {
+/// If floating point values are compared for equality this matcher should be used instead of [equal_to].
+/// The epsilon is absolute, use [close_to_relative] if the tolerance should scale with the magnitude of the expected value.
+/// The values only need to be `Clone`, so arbitrary-precision number types can be compared as well.
+pub fn close_to<'a, T>(expected: T, eps: T) -> Box<dyn Matcher<'a,T> + 'a>
+where T: Clone + PartialOrd + std::ops::Add<Output=T> + std::ops::Sub<Output=T> + Debug + 'a {
+    let lower = expected.clone() - eps.clone();
+    let upper = expected + eps;
     Box::new(move |actual: &T| {
         let builder = MatchResultBuilder::for_("close_to");
-        if &(expected - eps) <= actual && actual <= &(expected + eps) {
+        if &lower <= actual && actual <= &upper {
             builder.matched()
         } else {
             builder.failed_because(&format!("{:?} should be between {:?} and {:?}",
-                                            actual, expected - eps, expected + eps)
+                                            actual, lower, upper)
+            )
+        }
+    })
+}
+
+/// Matches if the asserted value is outside of the epsilon range around the expected value.
+///
+/// This is the negation of [close_to] but the failure reports the epsilon range the value unexpectedly fell into.
+/// Incomparable values, e.g., `NaN`, are considered to be outside of the range.
+pub fn not_close_to<'a, T>(expected: T, eps: T) -> Box<dyn Matcher<'a,T> + 'a>
+where T: Clone + PartialOrd + std::ops::Add<Output=T> + std::ops::Sub<Output=T> + Debug + 'a {
+    let lower = expected.clone() - eps.clone();
+    let upper = expected + eps;
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("not_close_to");
+        if &lower <= actual && actual <= &upper {
+            builder.failed_because(&format!("{:?} is unexpectedly between {:?} and {:?}",
+                                            actual, lower, upper)
+            )
+        } else {
+            builder.matched()
+        }
+    })
+}
+
+/// Matches if the asserted value is within a tolerance relative to the magnitude of the expected value.
+///
+/// The matcher is satisfied if `|actual - expected| <= rel_tol * |expected|`.
+/// Prefer this matcher over [close_to] if the compared values may have a large magnitude,
+/// as a fixed epsilon is then either too strict or too lenient.
+/// Use [close_to] for values near zero, as an `expected` value of zero only allows an exact match.
+pub fn close_to_relative<'a, T>(expected: T, rel_tol: T) -> Box<dyn Matcher<'a,T> + 'a>
+where T: Copy + PartialOrd + Default + std::ops::Add<Output=T> + std::ops::Sub<Output=T> + std::ops::Mul<Output=T>
+         + Debug + 'a {
+    Box::new(move |actual: &T| {
+        let builder = MatchResultBuilder::for_("close_to_relative");
+        let zero = T::default();
+        let difference = if *actual > expected { *actual - expected } else { expected - *actual };
+        let magnitude = if expected < zero { zero - expected } else { expected };
+        let allowed = rel_tol * magnitude;
+        if difference <= allowed {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} differs from {:?} by {:?} but should be between {:?} and {:?}",
+                                            actual, expected, difference, expected - allowed, expected + allowed)
             )
         }
     })
 }
 
+/// A floating point type which can be inspected by [is_nan], [is_finite], and [is_infinite].
+pub trait Float: Copy + Debug {
+    /// Returns `true` if the value is NaN.
+    fn is_nan(self) -> bool;
+    /// Returns `true` if the value is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+    /// Returns `true` if the value is positive or negative infinity.
+    fn is_infinite(self) -> bool;
+}
+
+macro_rules! impl_float {
+    ( $($float: ty),* ) => {$(
+        impl Float for $float {
+            fn is_nan(self) -> bool { <$float>::is_nan(self) }
+            fn is_finite(self) -> bool { <$float>::is_finite(self) }
+            fn is_infinite(self) -> bool { <$float>::is_infinite(self) }
+        }
+    )*}
+}
+
+impl_float!(f32, f64);
+
+/// Matches if the asserted floating point value is NaN.
+///
+/// NaN is never equal to any value, including itself, so [equal_to] cannot be used to check for it.
+pub fn is_nan<'a, T: Float + 'a>() -> Box<dyn Matcher<'a,T> + 'a> {
+    Box::new(|actual: &T| {
+        let builder = MatchResultBuilder::for_("is_nan");
+        if actual.is_nan() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not NaN", actual))
+        }
+    })
+}
+
+/// Matches if the asserted floating point value is neither infinite nor NaN.
+pub fn is_finite<'a, T: Float + 'a>() -> Box<dyn Matcher<'a,T> + 'a> {
+    Box::new(|actual: &T| {
+        let builder = MatchResultBuilder::for_("is_finite");
+        if actual.is_finite() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not finite", actual))
+        }
+    })
+}
+
+/// Matches if the asserted floating point value is positive or negative infinity.
+pub fn is_infinite<'a, T: Float + 'a>() -> Box<dyn Matcher<'a,T> + 'a> {
+    Box::new(|actual: &T| {
+        let builder = MatchResultBuilder::for_("is_infinite");
+        if actual.is_infinite() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is not infinite", actual))
+        }
+    })
+}
+
+/// Matches if the asserted `f64` is at most `max_ulps` representable values (units in the last place) away from the expected value.
+///
+/// In contrast to [close_to] the tolerance scales with the magnitude of the values.
+/// `0.0` and `-0.0` are zero ULPs apart and values of different sign are as far apart as the values between them.
+/// NaN is never within any distance, not even of NaN.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&(0.1 + 0.2), within_ulps(0.3, 1));
+/// ```
+pub fn within_ulps<'a>(expected: f64, max_ulps: u64) -> Box<dyn Matcher<'a,f64> + 'a> {
+    Box::new(move |actual: &f64| {
+        let builder = MatchResultBuilder::for_("within_ulps");
+        if actual.is_nan() || expected.is_nan() {
+            return builder.failed_because(&format!("{:?} and {:?} cannot be compared as one of them is NaN", actual, expected));
+        }
+        let distance = (ordered_bits(*actual) as i128 - ordered_bits(expected) as i128).unsigned_abs();
+        if distance <= max_ulps as u128 {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("{:?} is {} ULPs away from {:?} but at most {} are allowed",
+                                            actual, distance, expected, max_ulps))
+        }
+    })
+}
+
+/// Maps the bits of a float to an integer which is ordered like the float, with `0.0` and `-0.0` both mapped to `0`.
+fn ordered_bits(value: f64) -> i64 {
+    let bits = value.to_bits() as i64;
+    if bits < 0 { i64::MIN - bits } else { bits }
+}
+
+/// Matches if the asserted `f64` has exactly the same bit pattern as the expected value.
+///
+/// In contrast to [equal_to] `0.0` and `-0.0` are distinguished and NaNs with the same payload match each other.
+/// This is useful for round-trip tests of serializations.
+pub fn bit_equal_to<'a>(expected: f64) -> Box<dyn Matcher<'a,f64> + 'a> {
+    Box::new(move |actual: &f64| {
+        if actual.to_bits() == expected.to_bits() {
+            MatchResultBuilder::for_("bit_equal_to").matched()
+        } else {
+            MatchResult::Failed {
+                name: "bit_equal_to".to_owned(),
+                reason: format!("  Expected: {:?} ({:#018x})\n  Got: {:?} ({:#018x})",
+                                expected, expected.to_bits(), actual, actual.to_bits())
+            }
+        }
+    })
+}
+
+/// Matches if the asserted `f32` has exactly the same bit pattern as the expected value.
+///
+/// See [bit_equal_to].
+pub fn bit_equal_to_f32<'a>(expected: f32) -> Box<dyn Matcher<'a,f32> + 'a> {
+    Box::new(move |actual: &f32| {
+        if actual.to_bits() == expected.to_bits() {
+            MatchResultBuilder::for_("bit_equal_to_f32").matched()
+        } else {
+            MatchResult::Failed {
+                name: "bit_equal_to_f32".to_owned(),
+                reason: format!("  Expected: {:?} ({:#010x})\n  Got: {:?} ({:#010x})",
+                                expected, expected.to_bits(), actual, actual.to_bits())
+            }
+        }
+    })
+}
+
 /// Matches if asserted value and the expected value are truely the same object.
 ///
 /// The two values are the same if the reside at the same memory address.
-pub fn same_object<'a, T>(expected: &'a T) -> Box<Matcher<'a,T> + 'a>
+pub fn same_object<'a, T>(expected: &'a T) -> Box<dyn Matcher<'a,T> + 'a>
 where T: Debug + 'a {
     Box::new(move |actual: &T| {
         let builder = MatchResultBuilder::for_("same_object");
-        if (actual as *const _) == (expected as *const _) {
+        if std::ptr::eq(actual, expected) {
             builder.matched()
         } else {
             builder.failed_comparison(&actual, &expected)
         }
     })
 }
+
+/// Matches if the asserted slice lies within the memory of the expected `buffer`.
+///
+/// This is the case if the slice borrows (a part of) the `buffer`, e.g., when asserting zero-copy APIs.
+///
+/// # Examples
+/// ```rust,ignore
+/// let buffer = vec![1, 2, 3, 4];
+/// let tail = &buffer[2..];
+/// assert_that!(&tail, points_into(&buffer));
+/// ```
+pub fn points_into<'a, T: 'a>(buffer: &'a [T]) -> Box<dyn Matcher<'a,&'a [T]> + 'a> {
+    Box::new(move |actual: &&'a [T]| {
+        let builder = MatchResultBuilder::for_("points_into");
+        let actual_range = actual.as_ptr_range();
+        let buffer_range = buffer.as_ptr_range();
+        if buffer_range.start <= actual_range.start && actual_range.end <= buffer_range.end {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("slice at {:?}..{:?} does not lie within the buffer at {:?}..{:?}",
+                                            actual_range.start, actual_range.end, buffer_range.start, buffer_range.end))
+        }
+    })
+}
@@ -0,0 +1,90 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! The fs module contains matchers for asserting properties of paths in the file system.
+//!
+//! The matchers accept anything which can be viewed as a `&Path`, e.g., `PathBuf`, `&Path`, and `&str`.
+//! Symbolic links are followed.
+
+use std::fs::{metadata, FileType};
+use std::path::Path;
+use super::super::*;
+
+/// Returns a description of the kind of file for failure messages.
+fn describe(file_type: FileType) -> &'static str {
+    if file_type.is_dir() {
+        "a directory"
+    } else if file_type.is_file() {
+        "a file"
+    } else {
+        "neither a file nor a directory"
+    }
+}
+
+/// Matches if the asserted path exists.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&PathBuf::from("target"), path_exists());
+/// ```
+pub fn path_exists<'a,P>() -> Box<dyn Matcher<'a,P> + 'a>
+where P: AsRef<Path> + 'a {
+    Box::new(move |actual: &P| {
+        let builder = MatchResultBuilder::for_("path_exists");
+        let path = actual.as_ref();
+        match metadata(path) {
+            Ok(_) => builder.matched(),
+            Err(err) => builder.failed_because(&format!("{:?} cannot be accessed: {}", path, err))
+        }
+    })
+}
+
+/// Matches if the asserted path exists and is a regular file.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&PathBuf::from("Cargo.toml"), is_file());
+/// ```
+pub fn is_file<'a,P>() -> Box<dyn Matcher<'a,P> + 'a>
+where P: AsRef<Path> + 'a {
+    Box::new(move |actual: &P| {
+        let builder = MatchResultBuilder::for_("is_file");
+        let path = actual.as_ref();
+        match metadata(path) {
+            Ok(ref meta) if meta.is_file() => builder.matched(),
+            Ok(meta) => builder.failed_because(&format!("{:?} is {}", path, describe(meta.file_type()))),
+            Err(err) => builder.failed_because(&format!("{:?} cannot be accessed: {}", path, err))
+        }
+    })
+}
+
+/// Matches if the asserted path exists and is a directory.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&PathBuf::from("src"), is_dir());
+/// ```
+pub fn is_dir<'a,P>() -> Box<dyn Matcher<'a,P> + 'a>
+where P: AsRef<Path> + 'a {
+    Box::new(move |actual: &P| {
+        let builder = MatchResultBuilder::for_("is_dir");
+        let path = actual.as_ref();
+        match metadata(path) {
+            Ok(ref meta) if meta.is_dir() => builder.matched(),
+            Ok(meta) => builder.failed_because(&format!("{:?} is {}", path, describe(meta.file_type()))),
+            Err(err) => builder.failed_because(&format!("{:?} cannot be accessed: {}", path, err))
+        }
+    })
+}
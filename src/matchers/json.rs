@@ -0,0 +1,65 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! The json module contains matchers for asserting the structure of `serde_json::Value`s.
+//!
+//! Requires the `json` feature.
+
+use serde_json::Value;
+use super::super::*;
+
+/// Navigates the dotted `path` starting at `value`.
+///
+/// Array elements are addressed by their index, e.g., `"users.0.name"`.
+/// Returns the resolved value or the prefix of the path which could not be resolved.
+fn resolve<'a>(value: &'a Value, path: &str) -> Result<&'a Value, String> {
+    let mut current = value;
+    let mut resolved: Vec<&str> = Vec::new();
+    for segment in path.split('.') {
+        resolved.push(segment);
+        let next = match *current {
+            Value::Object(ref fields) => fields.get(segment),
+            Value::Array(ref elements) => segment.parse::<usize>().ok().and_then(|index| elements.get(index)),
+            _ => None
+        };
+        current = next.ok_or_else(|| resolved.join("."))?;
+    }
+    Ok(current)
+}
+
+/// Matches if the value at the dotted `path` of the asserted JSON value satisfies the given `Matcher`.
+///
+/// The segments of the `path` are separated by dots and either name a field of an object or the index of an array element.
+/// A failure reports either the part of the path which could not be resolved or the failure of the nested matcher.
+///
+/// # Examples
+/// ```rust,ignore
+/// let response = json!({ "user": { "name": "Alice", "roles": ["admin"] } });
+/// assert_that!(&response, json_has_field("user.name", eq(json!("Alice"))));
+/// assert_that!(&response, json_has_field("user.roles.0", eq(json!("admin"))));
+/// ```
+pub fn json_has_field<'a>(path: &str, matcher: Box<dyn Matcher<'a,Value> + 'a>) -> Box<dyn Matcher<'a,Value> + 'a> {
+    let path = path.to_owned();
+    Box::new(move |actual: &'a Value| {
+        let builder = MatchResultBuilder::for_(&format!("json_has_field({})", path));
+        match resolve(actual, &path) {
+            Ok(value) => match matcher.check(value) {
+                MatchResult::Matched { .. } => builder.matched(),
+                failed => builder.failed_because_of(&path, failed)
+            },
+            Err(unresolved) => builder.failed_because(&format!("path {:?} cannot be resolved", unresolved))
+        }
+    })
+}
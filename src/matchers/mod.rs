@@ -17,6 +17,36 @@ mod core;
 mod combinators;
 pub mod variant;
 pub mod collection;
+pub mod string;
+pub mod structure;
+pub mod set;
+pub mod time;
+pub mod fs;
+#[cfg(feature = "json")]
+pub mod json;
 
 pub use self::core::*;
 pub use self::combinators::*;
+
+use std::fmt::Display;
+use super::MatchResult;
+
+/// Prefixes the name of the `result` of a nested matcher with the `name` of the enclosing matcher.
+fn nested_result(name: &str, result: MatchResult) -> MatchResult {
+    match result {
+        MatchResult::Matched { name: nested_name } =>
+            MatchResult::Matched { name: format!("{}({})", name, nested_name) },
+        MatchResult::Failed { name: nested_name, reason } =>
+            MatchResult::Failed { name: format!("{}({})", name, nested_name), reason }
+    }
+}
+
+/// Appends the failure `result` of the matcher for `field` to the `failures` of an enclosing matcher.
+///
+/// The struct, tuple, and variant matchers list the failures of their fields with it, so they are formatted alike.
+#[doc(hidden)]
+pub fn push_field_failure<F: Display>(failures: &mut String, field: F, result: MatchResult) {
+    if let MatchResult::Failed { name, reason } = result {
+        failures.push_str(&format!("\n    {}: {}\n    {}", field, name, reason.replace("\n", "\n    ")));
+    }
+}
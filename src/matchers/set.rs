@@ -0,0 +1,47 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! The set module contains matchers for asserting properties of hash sets.
+
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::hash::Hash;
+use super::super::*;
+
+/// Matches if the asserted set contains exactly the expected elements.
+///
+/// The expected elements can be given by any collection, e.g., a `HashSet` or a `Vec`.
+/// The failure lists the elements only present in one of the sets separately.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&actual_set, set_equal_to(vec![1,2,3]));
+/// ```
+pub fn set_equal_to<'a,T:'a,I>(expected: I) -> Box<dyn Matcher<'a,HashSet<T>> + 'a>
+where T: Eq + Hash + Debug,
+      I: IntoIterator<Item=T> {
+    let expected: HashSet<T> = expected.into_iter().collect();
+    Box::new(move |actual: &'a HashSet<T>| {
+        let builder = MatchResultBuilder::for_("set_equal_to");
+        let missing: Vec<_> = expected.difference(actual).collect();
+        let unexpected: Vec<_> = actual.difference(&expected).collect();
+        if missing.is_empty() && unexpected.is_empty() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the sets differ\n\tOnly in expected: {:?}\n\tOnly in actual: {:?}",
+                                            missing, unexpected))
+        }
+    })
+}
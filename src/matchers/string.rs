@@ -0,0 +1,270 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! The string module contains matchers for asserting properties of strings.
+//!
+//! The matchers accept anything which can be viewed as a `&str`, e.g., `String` and `&str`.
+
+use std::fmt::Display;
+use std::str::FromStr;
+use super::super::*;
+
+/// The maximal number of characters of the actual string shown in a failure message.
+const MAX_REPR_CHARS: usize = 80;
+
+/// Returns a printable representation of `actual`, truncated with an ellipsis if it is too long.
+fn truncated(actual: &str) -> String {
+    match actual.char_indices().nth(MAX_REPR_CHARS) {
+        Some((idx, _)) => format!("{:?}...", &actual[..idx]),
+        None => format!("{:?}", actual)
+    }
+}
+
+/// Matches if the asserted string starts with the expected `prefix`.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&"Hello World", starts_with("Hello"));
+/// ```
+pub fn starts_with<'a,S:?Sized>(prefix: &str) -> Box<dyn Matcher<'a,S> + 'a>
+where S: AsRef<str> + 'a {
+    let prefix = prefix.to_owned();
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("starts_with");
+        let actual = actual.as_ref();
+        if !actual.starts_with(&prefix) {
+            builder.failed_because(
+                &format!("{} does not start with {:?}", truncated(actual), prefix)
+            )
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if the asserted string ends with the expected `suffix`.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&"Hello World", ends_with("World"));
+/// ```
+pub fn ends_with<'a,S:?Sized>(suffix: &str) -> Box<dyn Matcher<'a,S> + 'a>
+where S: AsRef<str> + 'a {
+    let suffix = suffix.to_owned();
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("ends_with");
+        let actual = actual.as_ref();
+        if !actual.ends_with(&suffix) {
+            builder.failed_because(
+                &format!("{} does not end with {:?}", truncated(actual), suffix)
+            )
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if the asserted string contains the expected `substring`.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&"Hello World", contains_substring("lo Wo"));
+/// ```
+pub fn contains_substring<'a,S:?Sized>(substring: &str) -> Box<dyn Matcher<'a,S> + 'a>
+where S: AsRef<str> + 'a {
+    let substring = substring.to_owned();
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("contains_substring");
+        let actual = actual.as_ref();
+        if !actual.contains(&substring) {
+            builder.failed_because(
+                &format!("{} does not contain {:?}", truncated(actual), substring)
+            )
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if the asserted string contains the expected `substring` ignoring the case of the characters.
+///
+/// Both strings are converted with `str::to_lowercase` before searching, see `equal_to_ignoring_case`.
+/// A failure reports the original strings.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&"ERROR: connection refused", contains_substring_ignoring_case("Connection Refused"));
+/// ```
+pub fn contains_substring_ignoring_case<'a,S:?Sized>(substring: &str) -> Box<dyn Matcher<'a,S> + 'a>
+where S: AsRef<str> + 'a {
+    let substring = substring.to_owned();
+    let substring_lowercase = substring.to_lowercase();
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("contains_substring_ignoring_case");
+        let actual = actual.as_ref();
+        if !actual.to_lowercase().contains(&substring_lowercase) {
+            builder.failed_because(
+                &format!("{} does not contain {:?} ignoring case", truncated(actual), substring)
+            )
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if the asserted string is equal to the `expected` string ignoring the case of the characters.
+///
+/// Both strings are compared after converting them with `str::to_lowercase`.
+/// The comparison is therefore Unicode-aware but independent of any locale.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&"Hello World", equal_to_ignoring_case("hello world"));
+/// ```
+pub fn equal_to_ignoring_case<'a,S:?Sized>(expected: &str) -> Box<dyn Matcher<'a,S> + 'a>
+where S: AsRef<str> + 'a {
+    let expected = expected.to_owned();
+    let expected_lowercase = expected.to_lowercase();
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("equal_to_ignoring_case");
+        let actual = actual.as_ref();
+        if actual.to_lowercase() != expected_lowercase {
+            builder.failed_comparison(&actual, &expected.as_str())
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if the asserted string consists of `expected` characters.
+///
+/// **Note:** the characters are counted as Unicode code points with `str::chars`,
+/// which differs from the length in bytes returned by `str::len` as soon as the string contains non-ASCII characters.
+/// A code point is not necessarily what a user perceives as a single character either, e.g., "é" may consist of two code points.
+/// The failure reports both counts to avoid confusion.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&"grüße", has_char_count(5)); // but 7 bytes
+/// ```
+pub fn has_char_count<'a,S:?Sized>(expected: usize) -> Box<dyn Matcher<'a,S> + 'a>
+where S: AsRef<str> + 'a {
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("has_char_count");
+        let actual = actual.as_ref();
+        let char_count = actual.chars().count();
+        if char_count != expected {
+            builder.failed_because(
+                &format!("{} has {} chars ({} bytes) instead of {} chars", truncated(actual), char_count, actual.len(), expected)
+            )
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if every character of the asserted string satisfies the given `predicate`.
+///
+/// The `description` of the predicate is used to report the first offending character.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&"abc123", all_chars_satisfy("is alphanumeric", |c| c.is_alphanumeric()));
+/// ```
+pub fn all_chars_satisfy<'a,S:?Sized,P>(description: &str, predicate: P) -> Box<dyn Matcher<'a,S> + 'a>
+where S: AsRef<str> + 'a,
+      P: Fn(char) -> bool + 'a {
+    let description = description.to_owned();
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("all_chars_satisfy");
+        let actual = actual.as_ref();
+        match actual.char_indices().find(|&(_, c)| !predicate(c)) {
+            Some((idx, c)) => builder.failed_because(
+                &format!("{:?} at byte index {} of {} does not satisfy: {}", c, idx, truncated(actual), description)
+            ),
+            None => builder.matched()
+        }
+    })
+}
+
+/// Matches if the asserted string can be parsed into an `F` which satisfies the given `Matcher`.
+///
+/// The string is parsed with `str::parse` and a failure reports the parse error.
+///
+/// The nested matcher is not a boxed matcher like `equal_to(42)` as the parsed value is created by the check
+/// and cannot be borrowed for the lifetime of the asserted string.
+/// Instead it must implement `for<'b> Matcher<'b,F>`, e.g., a closure creating and applying a boxed matcher on each check.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&"42", parses_as(|number: &i32| equal_to(42).check(number)));
+/// ```
+pub fn parses_as<'a,S:?Sized,F,M>(matcher: M) -> Box<dyn Matcher<'a,S> + 'a>
+where S: AsRef<str> + 'a,
+      F: FromStr,
+      F::Err: Display,
+      M: for<'b> Matcher<'b,F> + 'a {
+    Box::new(move |actual: &S| {
+        let actual = actual.as_ref();
+        match actual.parse::<F>() {
+            Ok(parsed) => match matcher.check(&parsed) {
+                MatchResult::Matched { name } => MatchResultBuilder::for_(&format!("parses_as({})", name)).matched(),
+                failed => MatchResultBuilder::for_(&format!("parses_as({})", failed.name()))
+                                             .failed_because_of(&format!("the value parsed from {}", truncated(actual)), failed)
+            },
+            Err(err) => MatchResultBuilder::for_("parses_as")
+                                           .failed_because(&format!("{} cannot be parsed: {}", truncated(actual), err))
+        }
+    })
+}
+
+/// Matches if the asserted bytes are valid UTF-8 and the decoded string satisfies the given `Matcher`.
+///
+/// A decoding failure reports the offset of the first invalid byte.
+/// The bytes are decoded in place, so the nested matcher inspects a `str` borrowed from the asserted bytes.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&encoded, decodes_utf8_as(equal_to_ignoring_case("Grüße")));
+/// ```
+pub fn decodes_utf8_as<'a,B>(matcher: Box<dyn Matcher<'a,str> + 'a>) -> Box<dyn Matcher<'a,B> + 'a>
+where B: AsRef<[u8]> + 'a {
+    Box::new(move |actual: &'a B| {
+        match std::str::from_utf8(actual.as_ref()) {
+            Ok(decoded) => match matcher.check(decoded) {
+                MatchResult::Matched { name } => MatchResultBuilder::for_(&format!("decodes_utf8_as({})", name)).matched(),
+                failed => MatchResultBuilder::for_(&format!("decodes_utf8_as({})", failed.name()))
+                                             .failed_because_of(&format!("the decoded string {}", truncated(decoded)), failed)
+            },
+            Err(err) => MatchResultBuilder::for_("decodes_utf8_as")
+                                           .failed_because(&format!("invalid UTF-8 at byte offset {}: {}", err.valid_up_to(), err))
+        }
+    })
+}
+
+/// Matches if the asserted string matches the regular expression `pattern`.
+///
+/// The `pattern` is compiled once when the matcher is created.
+/// Panics if `pattern` is not a valid regular expression.
+/// Requires the `regex` feature.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&"2017-04-05", matches_regex(r"^\d{4}-\d{2}-\d{2}$"));
+/// ```
+#[cfg(feature = "regex")]
+pub fn matches_regex<'a,S:?Sized>(pattern: &str) -> Box<dyn Matcher<'a,S> + 'a>
+where S: AsRef<str> + 'a {
+    let regex = ::regex::Regex::new(pattern).unwrap_or_else(|err| {
+        panic!("Invalid regular expression passed to matches_regex: {:?}\n{}", pattern, err)
+    });
+    Box::new(move |actual: &S| {
+        let builder = MatchResultBuilder::for_("matches_regex");
+        let actual = actual.as_ref();
+        if !regex.is_match(actual) {
+            builder.failed_because(
+                &format!("{} does not match the regular expression {:?}", truncated(actual), regex.as_str())
+            )
+        } else { builder.matched() }
+    })
+}
@@ -0,0 +1,228 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! The structure module contains matchers for asserting properties of the fields of structs.
+
+use super::super::*;
+
+/// Matches if the fields of the asserted struct satisfy the given matchers.
+///
+/// Each listed field is checked with its own `Matcher`.
+/// Fields which are not listed are ignored.
+/// All fields are checked even if one of them fails, so the failure lists every non-matching field with its reason.
+/// The matcher expressions are evaluated each time the struct is checked.
+///
+/// # Examples
+/// ```rust,ignore
+/// struct Point { x: i32, y: i32 }
+///
+/// assert_that!(&Point { x: 1, y: 2 }, structure!(Point { x: equal_to(1), y: greater_than(0) }));
+/// ```
+#[macro_export]
+macro_rules! structure {
+    ( $type: path { $( $field: ident : $matcher: expr ),* } ) => {
+        Box::new(|actual: &$type| {
+            #[allow(unused_imports)]
+            use galvanic_assert::{MatchResult, MatchResultBuilder, Matcher};
+            let builder = MatchResultBuilder::for_(concat!("structure<", stringify!($type), ">"));
+            #[allow(unused_mut)]
+            let mut failures = String::new();
+            $(
+                galvanic_assert::matchers::push_field_failure(&mut failures, stringify!($field), $matcher.check(&actual.$field));
+            )*
+            if failures.is_empty() {
+                builder.matched()
+            } else {
+                builder.failed_because(&format!("the following fields did not match:{}", failures))
+            }
+        })
+    };
+    ( $type: path { $( $field: ident : $matcher: expr ),+ , } ) => {
+        structure!($type { $( $field: $matcher ),* })
+    };
+}
+
+/// Matches if the part of the asserted value selected by the accessor satisfies the given matcher.
+///
+/// The accessor is a closure with a single parameter selecting a field (or any other place) of the asserted value.
+/// The type of the parameter has to be given unless it can be inferred from the context.
+/// A failure is reported under the source text of the accessor.
+///
+/// # Examples
+/// ```rust,ignore
+/// struct User { name: String, age: u32 }
+///
+/// assert_that!(&user, field!(|u: &User| u.age, greater_than(18)));
+/// assert_that!(&user, all_of!(field!(|u: &User| u.age, greater_than(18)),
+///                             field!(|u: &User| u.name, eq("Alice".to_owned()))));
+/// ```
+#[macro_export]
+macro_rules! field {
+    ( |$param: ident : $type: ty| $accessor: expr, $matcher: expr ) => {{
+        use galvanic_assert::matchers::structure::field;
+        field(stringify!($accessor), |$param: $type| &$accessor, $matcher)
+    }};
+    ( |$param: ident| $accessor: expr, $matcher: expr ) => {{
+        use galvanic_assert::matchers::structure::field;
+        field(stringify!($accessor), |$param| &$accessor, $matcher)
+    }};
+}
+
+/// Matches if the part of the asserted value returned by the `accessor` satisfies the given `Matcher`.
+///
+/// The `description` of the accessor is used to report a failure.
+/// Use the `field!` macro to derive the description from the accessor's source text.
+pub fn field<'a,T:'a,V:'a,A>(description: &str, accessor: A, matcher: Box<dyn Matcher<'a,V> + 'a>) -> Box<dyn Matcher<'a,T> + 'a>
+where A: Fn(&'a T) -> &'a V + 'a {
+    let description = description.to_owned();
+    Box::new(move |actual: &'a T| {
+        let builder = MatchResultBuilder::for_(&format!("field({})", description));
+        match matcher.check(accessor(actual)) {
+            MatchResult::Matched { .. } => builder.matched(),
+            failed => builder.failed_because_of(&description, failed)
+        }
+    })
+}
+
+/// A `Matcher` checking fields of the asserted struct which can be composed at runtime.
+///
+/// In contrast to `structure!` the checked fields need not be known at compile time, e.g., in parameterized tests.
+/// Use `new()` to create an empty `StructMatcher` and `field()` to add a check of a field.
+/// All fields are checked even if one of them fails and the failure is reported like by `structure!`.
+///
+/// # Examples
+/// ```rust,ignore
+/// struct Point { x: i32, y: i32 }
+///
+/// let mut matcher = StructMatcher::new();
+/// for &(name, bound) in &[("x", 5), ("y", 10)] {
+///     let accessor: fn(&Point) -> &i32 = if name == "x" { |p| &p.x } else { |p| &p.y };
+///     matcher = matcher.field(name, accessor, less_than(bound));
+/// }
+/// assert_that!(&Point { x: 1, y: 2 }, matcher);
+/// ```
+pub struct StructMatcher<'a, T:'a> {
+    fields: Vec<(String, Box<dyn Matcher<'a,T> + 'a>)>
+}
+
+impl<'a, T:'a> Default for StructMatcher<'a,T> {
+    fn default() -> StructMatcher<'a,T> {
+        StructMatcher::new()
+    }
+}
+
+impl<'a, T:'a> StructMatcher<'a,T> {
+    /// Creates a `StructMatcher` without any field checks, which matches every value.
+    pub fn new() -> StructMatcher<'a,T> {
+        StructMatcher { fields: Vec::new() }
+    }
+
+    /// Adds a check that the field selected by the `accessor` satisfies the given `Matcher`.
+    ///
+    /// The `name` of the field is used to report a failure.
+    pub fn field<V:'a,A>(mut self, name: &str, accessor: A, matcher: Box<dyn Matcher<'a,V> + 'a>) -> StructMatcher<'a,T>
+    where A: Fn(&'a T) -> &'a V + 'a {
+        self.fields.push((name.to_owned(), Box::new(move |actual: &'a T| matcher.check(accessor(actual)))));
+        self
+    }
+}
+
+impl<'a, T:'a> Matcher<'a,T> for StructMatcher<'a,T> {
+    fn check(&self, actual: &'a T) -> MatchResult {
+        let builder = MatchResultBuilder::for_("structure");
+        let mut failures = String::new();
+        for (field, matcher) in &self.fields {
+            super::push_field_failure(&mut failures, field, matcher.check(actual));
+        }
+        if failures.is_empty() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the following fields did not match:{}", failures))
+        }
+    }
+}
+
+/// Matches if the elements of the asserted tuple satisfy the given matchers positionally.
+///
+/// Tuples with two up to five elements are supported.
+/// All elements are checked even if one of them fails, so the failure lists every non-matching element index with its reason.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&(1, 2), tuple!(equal_to(1), greater_than(0)));
+/// ```
+#[macro_export]
+macro_rules! tuple {
+    ( $m0: expr, $m1: expr ) => {
+        galvanic_assert::matchers::structure::tuple2($m0, $m1)
+    };
+    ( $m0: expr, $m1: expr, $m2: expr ) => {
+        galvanic_assert::matchers::structure::tuple3($m0, $m1, $m2)
+    };
+    ( $m0: expr, $m1: expr, $m2: expr, $m3: expr ) => {
+        galvanic_assert::matchers::structure::tuple4($m0, $m1, $m2, $m3)
+    };
+    ( $m0: expr, $m1: expr, $m2: expr, $m3: expr, $m4: expr ) => {
+        galvanic_assert::matchers::structure::tuple5($m0, $m1, $m2, $m3, $m4)
+    };
+}
+
+/// Matches if the elements of the asserted pair satisfy the given matchers positionally. See `tuple!`.
+pub fn tuple2<'a,A:'a,B:'a>(m0: Box<dyn Matcher<'a,A> + 'a>, m1: Box<dyn Matcher<'a,B> + 'a>) -> Box<dyn Matcher<'a,(A,B)> + 'a> {
+    Box::new(move |actual: &'a (A,B)| {
+        tuple_result(vec![m0.check(&actual.0), m1.check(&actual.1)])
+    })
+}
+
+/// Matches if the elements of the asserted triple satisfy the given matchers positionally. See `tuple!`.
+pub fn tuple3<'a,A:'a,B:'a,C:'a>(m0: Box<dyn Matcher<'a,A> + 'a>, m1: Box<dyn Matcher<'a,B> + 'a>,
+                                 m2: Box<dyn Matcher<'a,C> + 'a>) -> Box<dyn Matcher<'a,(A,B,C)> + 'a> {
+    Box::new(move |actual: &'a (A,B,C)| {
+        tuple_result(vec![m0.check(&actual.0), m1.check(&actual.1), m2.check(&actual.2)])
+    })
+}
+
+/// Matches if the elements of the asserted 4-tuple satisfy the given matchers positionally. See `tuple!`.
+pub fn tuple4<'a,A:'a,B:'a,C:'a,D:'a>(m0: Box<dyn Matcher<'a,A> + 'a>, m1: Box<dyn Matcher<'a,B> + 'a>,
+                                      m2: Box<dyn Matcher<'a,C> + 'a>, m3: Box<dyn Matcher<'a,D> + 'a>)
+                                      -> Box<dyn Matcher<'a,(A,B,C,D)> + 'a> {
+    Box::new(move |actual: &'a (A,B,C,D)| {
+        tuple_result(vec![m0.check(&actual.0), m1.check(&actual.1), m2.check(&actual.2), m3.check(&actual.3)])
+    })
+}
+
+/// Matches if the elements of the asserted 5-tuple satisfy the given matchers positionally. See `tuple!`.
+pub fn tuple5<'a,A:'a,B:'a,C:'a,D:'a,E:'a>(m0: Box<dyn Matcher<'a,A> + 'a>, m1: Box<dyn Matcher<'a,B> + 'a>,
+                                           m2: Box<dyn Matcher<'a,C> + 'a>, m3: Box<dyn Matcher<'a,D> + 'a>,
+                                           m4: Box<dyn Matcher<'a,E> + 'a>) -> Box<dyn Matcher<'a,(A,B,C,D,E)> + 'a> {
+    Box::new(move |actual: &'a (A,B,C,D,E)| {
+        tuple_result(vec![m0.check(&actual.0), m1.check(&actual.1), m2.check(&actual.2),
+                          m3.check(&actual.3), m4.check(&actual.4)])
+    })
+}
+
+/// Combines the results of the element matchers of a tuple, reporting each failure under its element index.
+fn tuple_result(results: Vec<MatchResult>) -> MatchResult {
+    let builder = MatchResultBuilder::for_("tuple");
+    let mut failures = String::new();
+    for (index, result) in results.into_iter().enumerate() {
+        super::push_field_failure(&mut failures, index, result);
+    }
+    if failures.is_empty() {
+        builder.matched()
+    } else {
+        builder.failed_because(&format!("the following elements did not match:{}", failures))
+    }
+}
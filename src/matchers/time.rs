@@ -0,0 +1,50 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! The time module contains matchers for asserting properties of durations.
+
+use std::time::Duration;
+use super::super::*;
+
+/// Matches if the asserted duration differs from the `expected` duration by at most the given `tolerance`.
+///
+/// If `expected + tolerance` exceeds the maximum `Duration`, the asserted duration is not bounded from above.
+///
+/// # Examples
+/// ```rust,ignore
+/// let elapsed = start.elapsed();
+/// assert_that!(&elapsed, duration_within(Duration::from_millis(100), Duration::from_millis(20)));
+/// ```
+pub fn duration_within<'a>(expected: Duration, tolerance: Duration) -> Box<dyn Matcher<'a,Duration> + 'a> {
+    let lower = expected.checked_sub(tolerance).unwrap_or_else(|| Duration::new(0, 0));
+    let upper = expected.checked_add(tolerance);
+    Box::new(move |actual: &Duration| {
+        let builder = MatchResultBuilder::for_("duration_within");
+        let below_upper = match upper {
+            Some(upper) => actual <= &upper,
+            None => true
+        };
+        if &lower <= actual && below_upper {
+            builder.matched()
+        } else {
+            match upper {
+                Some(upper) => builder.failed_because(&format!("{:?} is not within {:?} of {:?}, i.e., between {:?} and {:?}",
+                                                               actual, tolerance, expected, lower, upper)),
+                None => builder.failed_because(&format!("{:?} is not within {:?} of {:?}, i.e., at least {:?}",
+                                                        actual, tolerance, expected, lower))
+            }
+        }
+    })
+}
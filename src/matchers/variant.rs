@@ -15,6 +15,9 @@
 
 //! The variant module contains matchers for asserting properties of enums and convienience functions for Option and Result.
 
+use std::any::type_name;
+use std::error::Error;
+use std::fmt::Debug;
 use super::super::*;
 
 /// Matches if the asserted value's variant matches the expected variant.
@@ -46,8 +49,152 @@ macro_rules! is_variant {
     }
 }
 
+/// Matches if the asserted value matches the given pattern and the optional guard holds.
+///
+/// Any pattern of a `match` arm can be used.
+/// The pattern is matched against the dereferenced value, so bindings of non-`Copy` values have to use `ref`.
+/// As the type of the asserted value is inferred, methods cannot be called on bindings in the guard;
+/// use operators or `satisfies` instead.
+/// If the pattern does not match, the failure states the pattern and the actual value.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&Some(3), matches_pattern!(Some(n) if n > 0));
+/// assert_that!(&(1, "one".to_owned()), matches_pattern!((1, ref s) if s == "one"));
+/// assert_that!(&'c', matches_pattern!('a'..='z'));
+/// ```
+#[macro_export]
+macro_rules! matches_pattern {
+    ( $pattern: pat $(if $guard: expr)? ) => {
+        Box::new(|actual: &_| {
+            use galvanic_assert::MatchResultBuilder;
+            let builder = MatchResultBuilder::for_("matches_pattern");
+            #[allow(unreachable_patterns)]
+            match actual {
+                &$pattern $(if $guard)? => builder.matched(),
+                _ => builder.failed_because(
+                        &format!("{:?} does not match the pattern '{}'", actual, stringify!($pattern $(if $guard)?))
+                )
+            }
+        })
+    }
+}
+
+/// Matches if the asserted value's variant matches the expected variant and its fields satisfy the given matchers.
+///
+/// The fields of tuple variants are matched positionally, the fields of struct variants by name.
+/// Fields of struct variants which are not listed are ignored.
+/// If the variant does not match, the failure states the expected variant and the actual value.
+/// The matcher expressions are evaluated each time a value is checked.
+///
+/// # Examples
+/// ```rust,ignore
+/// enum Event { Click(i32), Key { code: u32, shift: bool }, Quit }
+///
+/// assert_that!(&Event::Click(2), variant!(Event::Click(equal_to(2))));
+/// assert_that!(&Event::Key { code: 13, shift: false }, variant!(Event::Key { code: equal_to(13) }));
+/// assert_that!(&Event::Quit, variant!(Event::Quit));
+/// ```
+#[macro_export]
+macro_rules! variant {
+    ( @name $variant: path ) => {
+        format!("variant<{}>", stringify!($variant).replace(" ", ""))
+    };
+    ( @check_variant $actual: ident, $variant: path ) => {
+        match $actual {
+            &$variant {..} => {},
+            _ => return MatchResultBuilder::for_(&variant!(@name $variant))
+                        .failed_because(&format!("expected variant '{}' but got {:?}",
+                                                 stringify!($variant).replace(" ", ""), $actual))
+        }
+    };
+    ( @result $failures: ident, $variant: path ) => {{
+        let builder = MatchResultBuilder::for_(&variant!(@name $variant));
+        if $failures.is_empty() {
+            builder.matched()
+        } else {
+            builder.failed_because(&format!("the following fields did not match:{}", $failures))
+        }
+    }};
+    ( @failure $failures: ident, $field: expr, $result: expr ) => {
+        galvanic_assert::matchers::push_field_failure(&mut $failures, $field, $result);
+    };
+    ( @tuple $actual: ident, $failures: ident, $index: ident, $variant: path, [$($skip: tt)*], ) => {};
+    ( @tuple $actual: ident, $failures: ident, $index: ident, $variant: path, [$($skip: tt)*],
+      $matcher: expr $(, $matchers: expr)* ) => {
+        if let &$variant ( $($skip)* ref value, .. ) = $actual {
+            variant!(@failure $failures, $index, $matcher.check(value));
+        }
+        $index += 1;
+        variant!(@tuple $actual, $failures, $index, $variant, [$($skip)* _,], $($matchers),*);
+    };
+    ( @tuple_variant $variant: path, $($matcher: expr),* ) => {
+        Box::new(|actual: &_| {
+            #[allow(unused_imports)]
+            use galvanic_assert::{MatchResult, MatchResultBuilder, Matcher};
+            variant!(@check_variant actual, $variant);
+            #[allow(unused_mut)]
+            let mut failures = String::new();
+            #[allow(unused_mut, unused_variables, unused_assignments)]
+            let mut index = 0usize;
+            variant!(@tuple actual, failures, index, $variant, [], $($matcher),*);
+            variant!(@result failures, $variant)
+        })
+    };
+    ( @struct_variant $variant: path, $($field: ident : $matcher: expr),* ) => {
+        Box::new(|actual: &_| {
+            #[allow(unused_imports)]
+            use galvanic_assert::{MatchResult, MatchResultBuilder, Matcher};
+            variant!(@check_variant actual, $variant);
+            #[allow(unused_mut)]
+            let mut failures = String::new();
+            $(
+                if let &$variant { ref $field, .. } = actual {
+                    variant!(@failure failures, stringify!($field), $matcher.check($field));
+                }
+            )*
+            variant!(@result failures, $variant)
+        })
+    };
+    ( $($variant: ident)::+ ( $($matcher: expr),* ) ) => {
+        variant!(@tuple_variant $($variant)::+, $($matcher),*)
+    };
+    ( $variant: path { $($field: ident : $matcher: expr),* $(,)* } ) => {
+        variant!(@struct_variant $variant, $($field: $matcher),*)
+    };
+    ( $variant: path ) => {
+        Box::new(|actual: &_| {
+            #[allow(unused_imports)]
+            use galvanic_assert::{MatchResult, MatchResultBuilder, Matcher};
+            variant!(@check_variant actual, $variant);
+            MatchResultBuilder::for_(&variant!(@name $variant)).matched()
+        })
+    };
+}
+
+/// Matches if the asserted `Option` is `Some`.
+pub fn is_some<'a, T: 'a>() -> Box<dyn Matcher<'a,Option<T>> + 'a> {
+    Box::new(|maybe_actual: &Option<T>| {
+        let builder = MatchResultBuilder::for_("is_some");
+        if maybe_actual.is_none() {
+            builder.failed_because("passed Option is None")
+        } else { builder.matched() }
+    })
+}
+
+/// Matches if the asserted `Option` is `None`.
+pub fn is_none<'a, T: Debug + 'a>() -> Box<dyn Matcher<'a,Option<T>> + 'a> {
+    Box::new(|maybe_actual: &Option<T>| {
+        let builder = MatchResultBuilder::for_("is_none");
+        match maybe_actual {
+            Some(actual) => builder.failed_because(&format!("passed Option is Some({:?})", actual)),
+            &None => builder.matched()
+        }
+    })
+}
+
 /// Matches the contents of an `Option` againts a passed `Matcher`.
-pub fn maybe_some<'a, T: 'a>(matcher: Box<Matcher<'a,T> + 'a>) -> Box<Matcher<'a,Option<T>> + 'a> {
+pub fn maybe_some<'a, T: 'a>(matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,Option<T>> + 'a> {
     Box::new(move |maybe_actual: &'a Option<T>| {
         maybe_actual.as_ref()
                     .map_or(MatchResultBuilder::for_("maybe_some")
@@ -57,8 +204,48 @@ pub fn maybe_some<'a, T: 'a>(matcher: Box<Matcher<'a,T> + 'a>) -> Box<Matcher<'a
     })
 }
 
+/// Matches the contents of an `Option` againts a passed `Matcher`.
+///
+/// Fails if the `Option` is `None`.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&Some(4), some_value(greater_than(3)));
+/// ```
+pub fn some_value<'a, T: 'a>(matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,Option<T>> + 'a> {
+    Box::new(move |maybe_actual: &'a Option<T>| {
+        match maybe_actual.as_ref() {
+            Some(actual) => super::nested_result("some_value", matcher.check(actual)),
+            None => MatchResultBuilder::for_("some_value")
+                                       .failed_because("passed Option is None; no value present to evaluate nested matcher")
+        }
+    })
+}
+
+/// Matches if the asserted `Result` is `Ok`.
+pub fn is_ok<'a, T: Debug + 'a, E: Debug + 'a>() -> Box<dyn Matcher<'a,Result<T,E>> + 'a> {
+    Box::new(|maybe_actual: &Result<T,E>| {
+        let builder = MatchResultBuilder::for_("is_ok");
+        match maybe_actual {
+            &Ok(_) => builder.matched(),
+            Err(err) => builder.failed_because(&format!("passed Result is Err({:?})", err))
+        }
+    })
+}
+
+/// Matches if the asserted `Result` is `Err`.
+pub fn is_err<'a, T: Debug + 'a, E: Debug + 'a>() -> Box<dyn Matcher<'a,Result<T,E>> + 'a> {
+    Box::new(|maybe_actual: &Result<T,E>| {
+        let builder = MatchResultBuilder::for_("is_err");
+        match maybe_actual {
+            Ok(actual) => builder.failed_because(&format!("passed Result is Ok({:?})", actual)),
+            &Err(_) => builder.matched()
+        }
+    })
+}
+
 /// Matches the contents of a `Result` if it is `Ok` againts a passed `Matcher`.
-pub fn maybe_ok<'a, T: 'a, E: 'a>(matcher: Box<Matcher<'a,T> + 'a>) -> Box<Matcher<'a,Result<T,E>> + 'a> {
+pub fn maybe_ok<'a, T: 'a, E: 'a>(matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,Result<T,E>> + 'a> {
     Box::new(move |maybe_actual: &'a Result<T,E>| {
         match maybe_actual.as_ref() {
             Ok(actual) => matcher.check(actual),
@@ -69,7 +256,7 @@ pub fn maybe_ok<'a, T: 'a, E: 'a>(matcher: Box<Matcher<'a,T> + 'a>) -> Box<Match
 }
 
 /// Matches the contents of a `Result` if it is `Err` againts a passed `Matcher`.
-pub fn maybe_err<'a, T: 'a, E: 'a>(matcher: Box<Matcher<'a,E> + 'a>) -> Box<Matcher<'a,Result<T,E>> + 'a> {
+pub fn maybe_err<'a, T: 'a, E: 'a>(matcher: Box<dyn Matcher<'a,E> + 'a>) -> Box<dyn Matcher<'a,Result<T,E>> + 'a> {
     Box::new(move |maybe_actual: &'a Result<T,E>| {
         match maybe_actual.as_ref() {
             Err(actual) => matcher.check(actual),
@@ -78,3 +265,81 @@ pub fn maybe_err<'a, T: 'a, E: 'a>(matcher: Box<Matcher<'a,E> + 'a>) -> Box<Matc
         }
     })
 }
+
+/// Matches the contents of a `Result` if it is `Ok` againts a passed `Matcher`.
+///
+/// Fails if the `Result` is `Err`.
+///
+/// # Examples
+/// ```rust,ignore
+/// assert_that!(&"5".parse::<i32>(), ok_value(equal_to(5)));
+/// ```
+pub fn ok_value<'a, T: 'a, E: Debug + 'a>(matcher: Box<dyn Matcher<'a,T> + 'a>) -> Box<dyn Matcher<'a,Result<T,E>> + 'a> {
+    Box::new(move |maybe_actual: &'a Result<T,E>| {
+        match maybe_actual {
+            Ok(actual) => super::nested_result("ok_value", matcher.check(actual)),
+            Err(err) => MatchResultBuilder::for_("ok_value")
+                                 .failed_because(&format!("passed Result is Err({:?}); cannot evaluate nested matcher", err))
+        }
+    })
+}
+
+/// Matches the contents of a `Result` if it is `Err` againts a passed `Matcher`.
+///
+/// Fails with the debug representation of the value if the `Result` is `Ok`.
+/// Combine it with `variant!` to check the kind of an error.
+///
+/// # Examples
+/// ```rust,ignore
+/// let result: Result<i32, i32> = Err(2);
+/// assert_that!(&result, err_value(equal_to(2)));
+///
+/// let result: Result<File, io::ErrorKind> = File::open("missing").map_err(|e| e.kind());
+/// assert_that!(&result, err_value(variant!(io::ErrorKind::NotFound)));
+/// ```
+#[doc(alias = "err_matching")]
+pub fn err_value<'a, T: Debug + 'a, E: 'a>(matcher: Box<dyn Matcher<'a,E> + 'a>) -> Box<dyn Matcher<'a,Result<T,E>> + 'a> {
+    Box::new(move |maybe_actual: &'a Result<T,E>| {
+        match maybe_actual {
+            Err(err) => super::nested_result("err_value", matcher.check(err)),
+            Ok(actual) => MatchResultBuilder::for_("err_value")
+                                   .failed_because(&format!("passed Result is Ok({:?}); cannot evaluate nested matcher", actual))
+        }
+    })
+}
+
+/// An alias of `err_value` matching the error of a `Result`, e.g., an `io::ErrorKind` with `variant!`.
+///
+/// It is the very same matcher, so failures are reported under the name `err_value`.
+pub use self::err_value as err_matching;
+
+/// Matches if the asserted error or one of its sources is of type `E` and satisfies the passed `Matcher`.
+///
+/// The error chain is walked with `Error::source`, starting with the asserted error itself.
+/// A failure reports the displayed chain.
+///
+/// # Examples
+/// ```rust,ignore
+/// let error: Box<dyn Error> = Box::new(ConfigError::from(io_error));
+/// assert_that!(&error, has_source_matching(satisfies("is NotFound", |e: &io::Error| e.kind() == io::ErrorKind::NotFound)));
+/// ```
+// the asserted type is the boxed error returned by most APIs, hence it is borrowed as a box
+#[allow(clippy::borrowed_box)]
+pub fn has_source_matching<'a, E: Error + 'static>(matcher: Box<dyn Matcher<'a,E> + 'a>) -> Box<dyn Matcher<'a,Box<dyn Error>> + 'a> {
+    Box::new(move |actual: &'a Box<dyn Error>| {
+        let mut chain: Vec<String> = Vec::new();
+        let mut current: Option<&'a (dyn Error + 'static)> = Some(actual.as_ref());
+        while let Some(err) = current {
+            if let Some(source) = err.downcast_ref::<E>() {
+                if let MatchResult::Matched { name } = matcher.check(source) {
+                    return MatchResultBuilder::for_(&format!("has_source_matching({})", name)).matched();
+                }
+            }
+            chain.push(format!("\t{}: {}", chain.len(), err));
+            current = err.source();
+        }
+        MatchResultBuilder::for_("has_source_matching")
+            .failed_because(&format!("no source of type {} in the error chain satisfies the matcher:\n{}",
+                                     type_name::<E>(), chain.join("\n")))
+    })
+}
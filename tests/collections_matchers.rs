@@ -48,6 +48,31 @@ mod contains_in_any_order {
     }
 }
 
+mod contains_exactly {
+    use super::{std, contains_exactly};
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,2,3], contains_exactly(vec![2,3,2,1]));
+    }
+
+    #[test]
+    fn should_fail_due_to_missing_duplicate() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], contains_exactly(vec![2,3,2,1])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_unexpected_duplicate() {
+        assert_that!(
+            assert_that!(&vec![1,2,2,3], contains_exactly(vec![3,2,1])),
+            panics
+        );
+    }
+}
+
 mod contains_in_order {
     use super::{std, contains_in_order};
 
@@ -86,6 +111,91 @@ mod contains_in_order {
     }
 }
 
+mod equal_to_slices {
+    use super::{std, equal_to_slices};
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3,4], equal_to_slices(vec![1,2,3,4]));
+    }
+
+    #[test]
+    fn should_fail_with_first_difference() {
+        assert_that!(
+            assert_that!(&vec![1,2,5,6], equal_to_slices(vec![1,2,3,4])),
+            panics with message "First difference at index 2\n  Expected: 3\n  Got: 5"
+        );
+    }
+
+    #[test]
+    fn should_fail_with_whole_collections_if_lengths_differ() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], equal_to_slices(vec![1,2,3,4])),
+            panics with message "Expected: [1, 2, 3, 4]\n  Got: [1, 2, 3]"
+        );
+    }
+}
+
+mod equal_to_projected {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Event { kind: &'static str, timestamp: u64 }
+
+    fn event(kind: &'static str, timestamp: u64) -> Event {
+        Event { kind, timestamp }
+    }
+
+    #[test]
+    fn should_match_ignoring_rest_of_elements() {
+        let events = vec![event("start", 10), event("stop", 20)];
+        assert_that!(&events, equal_to_projected(vec![event("start", 0), event("stop", 0)], |e: &Event| e.kind));
+        assert_that!(events[0].timestamp < events[1].timestamp);
+    }
+
+    #[test]
+    fn should_fail_with_first_differing_index() {
+        assert_that!(
+            assert_that!(&vec![event("start", 10), event("stop", 20)],
+                         equal_to_projected(vec![event("start", 0), event("pause", 0)], |e: &Event| e.kind)),
+            panics with message "First difference at index 1\n  Expected: \"pause\"\n  Got: \"stop\""
+        );
+    }
+}
+
+mod close_to_elements {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![0.1 + 0.2, 1.0], close_to_elements(vec![0.3, 1.0], 1e-9));
+    }
+
+    #[test]
+    fn should_fail_with_first_differing_index() {
+        assert_that!(
+            assert_that!(&vec![1.0, 2.0, 3.5, 4.5], close_to_elements(vec![1.0, 2.0, 3.0, 4.0], 0.1)),
+            panics with message "elements at index 2 differ by more than 0.1\n\tExpected: 3.0\n\tGot: 3.5"
+        );
+    }
+
+    #[test]
+    fn should_fail_if_lengths_differ() {
+        assert_that!(
+            assert_that!(&vec![1.0], close_to_elements(vec![1.0, 2.0], 0.1)),
+            panics with message "Expected length: 2\n\tActual length: 1"
+        );
+    }
+
+    #[test]
+    fn should_fail_on_nan() {
+        assert_that!(
+            assert_that!(&vec![f64::NAN], close_to_elements(vec![1.0], 0.1)),
+            panics with message "elements at index 0"
+        );
+    }
+}
+
 mod contains_subset {
     use super::{std, contains_subset};
 
@@ -113,6 +223,128 @@ mod contains_subset {
     }
 }
 
+mod contains_subsequence {
+    use super::contains_subsequence;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3,4,5], contains_subsequence(vec![2,4,5]));
+    }
+
+    #[test]
+    fn should_match_empty_subsequence() {
+        assert_that!(&vec![1,2,3], contains_subsequence::<i32, Vec<i32>>(Vec::new()));
+    }
+
+    #[test]
+    fn should_match_with_repeated_elements() {
+        assert_that!(&vec![1,2,1,2], contains_subsequence(vec![1,1,2]));
+    }
+
+    #[test]
+    fn should_match_combined_with_other_matchers() {
+        assert_that!(&vec![1,2,3,4,5], all_of!(contains_subsequence(vec![1,3]),
+                                               contains_subsequence(vec![2,5])));
+    }
+
+    #[test]
+    fn should_fail_due_to_wrong_order() {
+        assert_that!(
+            assert_that!(&vec![1,2,3,4,5], contains_subsequence(vec![4,2])),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_due_to_missing_element() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], contains_subsequence(vec![1,5])),
+            panics
+        );
+    }
+}
+
+mod contains {
+    use super::contains;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3], contains(2));
+    }
+
+    #[test]
+    fn should_match_slice() {
+        let slice: &[i32] = &[1,2,3];
+        assert_that!(slice, contains(3));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], contains(4)),
+            panics with message "[1, 2, 3] does not contain: 4"
+        );
+    }
+}
+
+mod starts_with_elements {
+    use super::starts_with_elements;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3], starts_with_elements(vec![1,2]));
+        assert_that!(&vec![1,2,3], starts_with_elements(vec![]));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], starts_with_elements(vec![2,3])),
+            panics with message "expected prefix [2, 3] but the collection starts with [1, 2]"
+        );
+    }
+
+    #[test]
+    fn should_fail_if_prefix_is_longer() {
+        assert_that!(
+            assert_that!(&vec![1], starts_with_elements(vec![1,2])),
+            panics with message "expected prefix [1, 2] but the collection starts with [1]"
+        );
+    }
+}
+
+mod ends_with_elements {
+    use super::ends_with_elements;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3], ends_with_elements(vec![2,3]));
+    }
+
+    #[test]
+    fn should_match_slice() {
+        let slice: &[i32] = &[1,2,3];
+        assert_that!(slice, ends_with_elements(vec![3]));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], ends_with_elements(vec![1,2])),
+            panics with message "expected suffix [1, 2] but the collection ends with [2, 3]"
+        );
+    }
+
+    #[test]
+    fn should_fail_if_suffix_is_longer() {
+        assert_that!(
+            assert_that!(&vec![3], ends_with_elements(vec![2,3])),
+            panics with message "expected suffix [2, 3] but the collection ends with [3]"
+        );
+    }
+}
+
 mod contained_in {
     use super::{std, contained_in};
 
@@ -130,6 +362,222 @@ mod contained_in {
     }
 }
 
+mod is_subset_of {
+    use super::is_subset_of;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec!["read", "read", "write"], is_subset_of(vec!["read", "write", "admin"]));
+        assert_that!(&Vec::<i32>::new(), is_subset_of(vec![1]));
+    }
+
+    #[test]
+    fn should_fail_with_elements_not_found() {
+        assert_that!(
+            assert_that!(&vec![1,2,3,4], is_subset_of(vec![1,3])),
+            panics with message "the following elements are not contained in [1, 3]: [2, 4]"
+        );
+    }
+}
+
+mod is_superset_of {
+    use super::is_superset_of;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3], is_superset_of(vec![3,1,1]));
+    }
+
+    #[test]
+    fn should_fail_with_missing_elements() {
+        assert_that!(
+            assert_that!(&vec![1,2,3], is_superset_of(vec![1,4,5])),
+            panics with message "the following elements are missing: [4, 5]"
+        );
+    }
+}
+
+mod contains_all {
+    use super::*;
+
+    #[test]
+    fn should_match_ignoring_extras_and_order() {
+        assert_that!(&vec![5, 1, 4, 2], contains_all(vec![1, 2]));
+    }
+
+    #[test]
+    fn should_fail_with_missing_elements() {
+        assert_that!(
+            assert_that!(&vec![5, 1, 4], contains_all(vec![1, 2, 3])),
+            panics with message "the following elements are missing: [2, 3]"
+        );
+    }
+}
+
+mod contains_none {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![5, 1, 4], contains_none(vec![2, 3]));
+        assert_that!(&Vec::<i32>::new(), contains_none(vec![2, 3]));
+    }
+
+    #[test]
+    fn should_fail_with_found_elements_and_indices() {
+        assert_that!(
+            assert_that!(&vec![2, 1, 4, 2], contains_none(vec![2, 3, 4])),
+            panics with message "the following forbidden elements are present:\n\t2 at indices [0, 3]\n\t4 at indices [2]"
+        );
+    }
+}
+
+mod has_length {
+    use super::has_length;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3], has_length(3));
+    }
+
+    #[test]
+    fn should_match_empty_collection() {
+        assert_that!(&Vec::<i32>::new(), has_length(0));
+    }
+
+    #[test]
+    fn should_match_array() {
+        assert_that!(&[1,2,3], has_length(3));
+    }
+
+    #[test]
+    fn should_match_slice() {
+        let slice: &[i32] = &[1,2,3];
+        assert_that!(slice, has_length(3));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,2,3,4,5], has_length(3)),
+            panics
+        );
+    }
+}
+
+mod has_length_between {
+    use super::*;
+
+    #[test]
+    fn should_match_inclusive_bounds() {
+        assert_that!(&vec![1], has_length_between(1, 3));
+        assert_that!(&vec![1,2,3], has_length_between(1, 3));
+    }
+
+    #[test]
+    fn should_fail_with_range_and_length() {
+        assert_that!(
+            assert_that!(&vec![1,2,3,4], has_length_between(1, 3)),
+            panics with message "expected length within [1, 3] but got 4"
+        );
+        assert_that!(
+            assert_that!(&Vec::<i32>::new(), has_length_between(1, 3)),
+            panics with message "expected length within [1, 3] but got 0"
+        );
+    }
+}
+
+mod yields_count {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&(1..10).filter(|x| x % 3 == 0), yields_count(3));
+        assert_that!(&std::iter::empty::<i32>(), yields_count(0));
+    }
+
+    #[test]
+    fn should_fail_with_actual_count() {
+        assert_that!(
+            assert_that!(&vec![1, 2].into_iter().map(|x| x * 2), yields_count(3)),
+            panics with message "expected 3 items but the iterator yielded 2"
+        );
+    }
+}
+
+mod same_length_as {
+    use super::same_length_as;
+
+    #[test]
+    fn should_match() {
+        let input = vec![1,2,3];
+        assert_that!(&vec!["a","b","c"], same_length_as(&input));
+    }
+
+    #[test]
+    fn should_fail() {
+        let input = vec![1,2,3];
+        assert_that!(
+            assert_that!(&vec!["a","b"], same_length_as(&input)),
+            panics with message "Expected length: 3\n\tActual length: 2"
+        );
+    }
+}
+
+mod has_length_matching {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,2,3], has_length_matching(|length: &usize| greater_than(2).check(length)));
+    }
+
+    #[test]
+    fn should_fail_with_actual_length() {
+        assert_that!(
+            assert_that!(&vec![1,2], has_length_matching(|length: &usize| greater_than(2).check(length))),
+            panics with message "length 2 does not match greater_than:\n    Bound: 2\n    Got: 2"
+        );
+    }
+}
+
+mod is_empty {
+    use super::{std, is_empty};
+
+    #[test]
+    fn should_match_empty_vec() {
+        assert_that!(&Vec::<i32>::new(), is_empty());
+    }
+
+    #[test]
+    fn should_match_empty_strings() {
+        assert_that!(&"", is_empty());
+        assert_that!(&String::new(), is_empty());
+    }
+
+    #[test]
+    fn should_match_empty_map() {
+        assert_that!(&std::collections::HashMap::<i32,i32>::new(), is_empty());
+    }
+
+    #[test]
+    fn should_fail_for_non_empty_vec() {
+        assert_that!(
+            assert_that!(&vec![1,2], is_empty()),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_non_empty_string() {
+        assert_that!(
+            assert_that!(&"foo", is_empty()),
+            panics
+        );
+    }
+}
+
 mod sorted_by {
     use super::{std, sorted_by};
 
@@ -321,6 +769,90 @@ mod sorted_strictly_by_in_any_order {
     }
 }
 
+mod is_sorted {
+    use super::{std, is_sorted};
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1.0,2.5,2.5,4.0], is_sorted());
+    }
+
+    #[test]
+    fn should_match_empty_collection() {
+        let v: Vec<f64> = Vec::new();
+        assert_that!(&v, is_sorted());
+    }
+
+    #[test]
+    fn should_fail_with_first_violation() {
+        assert_that!(
+            assert_that!(&vec![1.0,3.0,2.0,1.0], is_sorted()),
+            panics with message "elements at index 1 and 2 are out of order: 3.0, 2.0"
+        );
+    }
+
+    #[test]
+    fn should_fail_on_incomparable_elements() {
+        assert_that!(
+            assert_that!(&vec![1.0,f64::NAN], is_sorted()),
+            panics
+        );
+    }
+}
+
+mod is_sorted_by {
+    use super::{std, is_sorted_by};
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec!["a","bb","cc","ddd"], is_sorted_by(|a: &&str, b: &&str| a.len() <= b.len()));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec!["a","ccc","bb"], is_sorted_by(|a: &&str, b: &&str| a.len() <= b.len())),
+            panics with message "elements at index 1 and 2 are out of order: \"ccc\", \"bb\""
+        );
+    }
+}
+
+mod is_sorted_by_key {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec!["a", "bb", "cc", "ddd"], is_sorted_by_key(|s: &&str| s.len()));
+        assert_that!(&Vec::<&str>::new(), is_sorted_by_key(|s: &&str| s.len()));
+    }
+
+    #[test]
+    fn should_fail_with_first_violating_pair() {
+        assert_that!(
+            assert_that!(&vec!["a", "ccc", "bb"], is_sorted_by_key(|s: &&str| s.len())),
+            panics with message "keys of elements at index 1 and 2 are out of order: 3, 2"
+        );
+    }
+}
+
+mod has_unique_keys {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![(1, "a"), (2, "a"), (3, "b")], has_unique_keys(|&(id, _): &(i32, &str)| id));
+        assert_that!(&Vec::<i32>::new(), has_unique_keys(|x: &i32| *x));
+    }
+
+    #[test]
+    fn should_fail_with_duplicated_key_and_indices() {
+        assert_that!(
+            assert_that!(&vec![(1, "a"), (2, "b"), (1, "c")], has_unique_keys(|&(id, _): &(i32, &str)| id)),
+            panics with message "elements at index 0 and 2 have the same key 1"
+        );
+    }
+}
+
 mod sorted_ascending {
     use super::{std, sorted_ascending};
 
@@ -480,6 +1012,119 @@ mod all_elements_satisfy {
     }
 }
 
+mod all_elements {
+    use super::all_elements;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![0,1,2], all_elements(greater_than_or_equal(0)));
+    }
+
+    #[test]
+    fn should_match_empty_collection() {
+        assert_that!(&Vec::<i32>::new(), all_elements(less_than(0)));
+    }
+
+    #[test]
+    fn should_match_slice() {
+        let slice: &[i32] = &[0,1,2];
+        assert_that!(slice, all_elements(less_than(3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "element at index 2")]
+    fn should_fail() {
+        assert_that!(&vec![0,1,-2,-3], all_elements(greater_than_or_equal(0)));
+    }
+}
+
+mod any_element {
+    use super::any_element;
+    use galvanic_assert::matchers::*;
+    use galvanic_assert::matchers::string::starts_with;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec!["info: started", "error: failed"], any_element(starts_with("error")));
+    }
+
+    #[test]
+    fn should_fail_for_empty_collection() {
+        assert_that!(
+            assert_that!(&Vec::<i32>::new(), any_element(assertion_always_succeeds())),
+            panics
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "none of the 3 elements matches greater_than")]
+    fn should_fail() {
+        assert_that!(&vec![0,1,2], any_element(greater_than(2)));
+    }
+}
+
+mod count_matching {
+    use super::count_matching;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,5,2,7], count_matching(2, greater_than(4)));
+    }
+
+    #[test]
+    fn should_match_none() {
+        assert_that!(&Vec::<i32>::new(), count_matching(0, greater_than(4)));
+    }
+
+    #[test]
+    fn should_fail_with_matching_indices() {
+        assert_that!(
+            assert_that!(&vec![1,5,2,7], count_matching(1, greater_than(4))),
+            panics with message "expected 1 matching elements but got 2\n\tMatching indices: [1, 3]"
+        );
+    }
+}
+
+mod at_least_matching {
+    use super::at_least_matching;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,5,2,7], at_least_matching(2, greater_than(4)));
+        assert_that!(&vec![1,5,2,7], at_least_matching(1, greater_than(4)));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,5,2,7], at_least_matching(3, greater_than(4))),
+            panics with message "expected at least 3 elements matching greater_than but got 2"
+        );
+    }
+}
+
+mod at_most_matching {
+    use super::at_most_matching;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&vec![1,5,2,7], at_most_matching(2, greater_than(4)));
+        assert_that!(&vec![1,5,2,7], at_most_matching(3, greater_than(4)));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&vec![1,5,2,7], at_most_matching(1, greater_than(4))),
+            panics with message "expected at most 1 elements matching greater_than but got 2"
+        );
+    }
+}
+
 mod some_elements_satisfy {
     use super::{std, some_elements_satisfy};
 
@@ -572,6 +1217,89 @@ mod has_entry {
                 panics
             );
         }
+
+        #[test]
+        fn should_report_missing_key() {
+            let map = std::collections::HashMap::<i32,i32>::new();
+
+            assert_that!(
+                assert_that!(&map, has_entry(1, 2)),
+                panics with message "No entry with key 1 found"
+            );
+        }
+
+        #[test]
+        fn should_report_different_value() {
+            let mut map = std::collections::HashMap::<i32,i32>::new();
+            map.insert(1, 1);
+
+            assert_that!(
+                assert_that!(&map, has_entry(1, 2)),
+                panics with message "Expected: 2\n\tGot: 1"
+            );
+        }
+
+        #[test]
+        fn should_match_multiple_entries() {
+            use galvanic_assert::matchers::*;
+            let mut map = std::collections::HashMap::<i32,i32>::new();
+            map.insert(1, 2);
+            map.insert(3, 4);
+
+            assert_that!(&map, all_of!(has_entry(1, 2), has_entry(3, 4)));
+        }
+    }
+}
+
+mod has_entries {
+    use super::{std, has_entries};
+
+    #[test]
+    fn should_match() {
+        let mut map = std::collections::HashMap::<&str,i32>::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+        map.insert("c", 3);
+
+        assert_that!(&map, has_entries(vec![("a", 1), ("c", 3)]));
+    }
+
+    #[test]
+    fn should_report_missing_keys_and_different_values() {
+        let mut map = std::collections::BTreeMap::<&str,i32>::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        assert_that!(
+            assert_that!(&map, has_entries(vec![("a", 1), ("b", 3), ("c", 3), ("d", 4)])),
+            panics with message "not all entries are present\n\tMissing keys: [\"c\", \"d\"]\n\tDifferent values:\n\t\t\"b\": expected 3 but got 2"
+        );
+    }
+}
+
+mod has_entry_matching {
+    use super::{std, has_entry_matching};
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_match() {
+        let mut map = std::collections::HashMap::<i32,i32>::new();
+        map.insert(1, 2);
+        map.insert(5, 10);
+
+        assert_that!(&map, has_entry_matching(greater_than(3), less_than(20)));
+    }
+
+    #[test]
+    fn should_fail_if_no_entry_matches_both() {
+        let mut map = std::collections::BTreeMap::<i32,i32>::new();
+        map.insert(1, 20);
+        map.insert(5, 2);
+
+        assert_that!(
+            assert_that!(&map, has_entry_matching(greater_than(3), greater_than(10))),
+            panics with message "none of the 2 examined entries matches both the key and the value matcher"
+        );
     }
 }
 
@@ -622,5 +1350,25 @@ mod has_key {
                 panics
             );
         }
+
+        #[test]
+        fn should_report_present_keys() {
+            let mut map = std::collections::HashMap::<&str,i32>::new();
+            map.insert("colour", 2);
+
+            assert_that!(
+                assert_that!(&map, has_key("color")),
+                panics with message "Present keys: [\"colour\"]"
+            );
+        }
+
+        #[test]
+        fn should_not_require_debug_values() {
+            struct NoDebug;
+            let mut map = std::collections::HashMap::<i32,NoDebug>::new();
+            map.insert(1, NoDebug);
+
+            assert_that!(&map, has_key(1));
+        }
     }
 }
@@ -84,6 +84,66 @@ mod any_of {
     }
 }
 
+mod labels {
+    use super::*;
+
+    #[test]
+    fn should_prefix_failing_matcher_of_all_of_with_index() {
+        assert_that!(
+            assert_that!(&5, all_of!(lt(10), gt(0), lt(3))),
+            panics with message "Failed assertion of matcher: [2] less_than"
+        );
+    }
+
+    #[test]
+    fn should_prefix_failing_matcher_of_any_of_with_index() {
+        assert_that!(
+            assert_that!(&5, any_of!(lt(3), gt(7))),
+            panics with message "Failed assertion of matcher: [0] less_than"
+        );
+    }
+
+    #[test]
+    fn should_prefix_failing_matcher_with_label() {
+        assert_that!(&5, labeled_all_of!(("lower bound", gt(0)), ("upper bound", lt(10))));
+        assert_that!(
+            assert_that!(&15, labeled_all_of!(("lower bound", gt(0)), ("upper bound", lt(10)))),
+            panics with message "Failed assertion of matcher: [upper bound] less_than"
+        );
+    }
+}
+
+mod matcher_ext {
+    use super::*;
+
+    #[test]
+    fn should_combine_conjunctively() {
+        assert_that!(&5, gt(0).and_also(lt(10)));
+        assert_that!(
+            assert_that!(&15, gt(0).and_also(lt(10))),
+            panics with message "less_than"
+        );
+    }
+
+    #[test]
+    fn should_combine_disjunctively() {
+        assert_that!(&42, lt(10).or_else_match(eq(42)));
+        assert_that!(
+            assert_that!(&15, lt(10).or_else_match(eq(42))),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_group_from_left_to_right() {
+        assert_that!(&42, gt(0).and_also(lt(10)).or_else_match(eq(42)));
+        assert_that!(
+            assert_that!(&5, lt(10).or_else_match(eq(42)).and_also(gt(7))),
+            panics
+        );
+    }
+}
+
 mod combining_combinators {
     use super::*;
 
@@ -128,3 +188,61 @@ mod combining_variant_matchers {
         ));
     }
 }
+
+mod none_of {
+    use super::*;
+
+    #[test]
+    fn should_match_if_all_fail() {
+        let x = 1;
+        assert_that!(&x, NoneOf::of(assertion_always_fails())
+                                .nor(assertion_always_fails())
+        );
+    }
+
+    #[test]
+    fn should_match_with_macro() {
+        assert_that!(&1, none_of!(equal_to(2), greater_than(3), less_than(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "greater_than is satisfied")]
+    fn should_fail_if_one_matches() {
+        assert_that!(&1, none_of!(equal_to(2), greater_than(0), less_than(0)));
+    }
+}
+
+mod one_of {
+    use super::*;
+
+    #[test]
+    fn should_match_if_exactly_one_matches() {
+        let x = 1;
+        assert_that!(&x, OneOf::of(assertion_always_succeeds())
+                               .xor(assertion_always_fails())
+        );
+    }
+
+    #[test]
+    fn should_match_with_macro() {
+        let x = 1;
+        assert_that!(&x, one_of!(assertion_always_fails(), assertion_always_succeeds(), assertion_always_fails()));
+    }
+
+    #[test]
+    fn should_match_with_function() {
+        assert_that!(&1, xor(less_than(2), greater_than(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "more than one matcher matched")]
+    fn should_fail_if_both_match() {
+        assert_that!(&1, xor(less_than(2), greater_than(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "none of the matchers matched")]
+    fn should_fail_if_none_matches() {
+        assert_that!(&1, one_of!(assertion_always_fails(), assertion_always_fails()));
+    }
+}
@@ -35,216 +35,1196 @@ mod assert_panic {
         });
         assert!(panicked.is_err());
     }
+
+    struct PanicsOnDrop;
+
+    impl Drop for PanicsOnDrop {
+        fn drop(&mut self) { panic!("dropped"); }
+    }
+
+    #[test]
+    fn should_assert_a_panic_while_dropping_the_value() {
+        assert_that!(PanicsOnDrop, panics);
+    }
+}
+
+mod assert_panic_with_message {
+    use super::*;
+
+    #[test]
+    fn should_assert_a_panic_with_str_message() {
+        assert_that!(panic!("index out of bounds"), panics with message "out of bounds");
+    }
+
+    #[test]
+    fn should_assert_a_panic_with_formatted_message() {
+        assert_that!(panic!("index {} out of bounds", 3), panics with message "3 out of bounds");
+    }
+
+    #[test]
+    fn should_fail_if_no_panic_occurred() {
+        let panicked = std::panic::catch_unwind(|| {
+            assert_that!(&1+1, panics with message "out of bounds");
+        });
+        assert!(panicked.is_err());
+    }
+
+    #[test]
+    fn should_fail_if_message_differs() {
+        let panicked = std::panic::catch_unwind(|| {
+            assert_that!(panic!("division by zero"), panics with message "out of bounds");
+        });
+        assert!(panicked.is_err());
+    }
+}
+
+mod assert_panic_with_type {
+    use super::*;
+
+    #[derive(Debug)]
+    struct CustomError;
+
+    #[test]
+    fn should_assert_panic_with_type() {
+        assert_that!(std::panic::panic_any(CustomError), panics with type CustomError);
+        assert_that!(panic!("{}", 1), panics with type String);
+    }
+
+    #[test]
+    fn should_fail_to_assert_panic_with_another_type() {
+        assert_that!(
+            assert_that!(std::panic::panic_any(42), panics with type CustomError),
+            panics with message "expected expression to panic with a payload of type CustomError\n    Got: a payload of another type"
+        );
+    }
+
+    #[test]
+    fn should_fail_to_assert_panic_with_type_without_panic() {
+        assert_that!(
+            assert_that!(1 + 1, panics with type CustomError),
+            panics with message "expected expression to panic with a payload of type CustomError"
+        );
+    }
+}
+
+mod assert_does_not_panic {
+    use super::*;
+
+    #[test]
+    fn should_assert_that_no_panic_occurred() {
+        assert_that!(&1+1, does not panic);
+    }
+
+    #[test]
+    fn should_fail_to_assert_a_panic() {
+        let panicked = std::panic::catch_unwind(|| {
+            assert_that!(panic!("panic"), does not panic);
+        });
+        assert!(panicked.is_err());
+    }
+}
+
+mod assert_keywords {
+    // the keyword arms do not require the matchers to be imported
+
+    #[test]
+    fn should_assert_with_keywords() {
+        assert_that!(&1, equals 1);
+        assert_that!(&1, is less than 2);
+        assert_that!(&1, is greater than 0);
+    }
+
+    #[test]
+    fn should_assert_equality_with_literals() {
+        assert_that!(&5, 5);
+        assert_that!(&-5, -5);
+        assert_that!(&"galvanic", "galvanic");
+        assert_that!(&true, true);
+        assert_that!(
+            assert_that!(&5, 6),
+            panics with message "Failed assertion of matcher: equal\n  Expected: 6\n  Got: 5"
+        );
+    }
+
+    #[test]
+    fn should_convert_values_into_matchers() {
+        use galvanic_assert::matchers::ToMatcher;
+        assert_that!(&vec![1, 2], vec![1, 2].into_matcher());
+    }
+
+    #[test]
+    fn should_fail_with_keywords() {
+        assert_that!(
+            assert_that!(&1, equals 2),
+            panics with message "Failed assertion of matcher: equal\n  Expected: 2\n  Got: 1"
+        );
+        assert_that!(
+            assert_that!(&1, is less than 1),
+            panics with message "Failed assertion of matcher: less_than"
+        );
+        assert_that!(
+            assert_that!(&1, is greater than 1),
+            panics with message "Failed assertion of matcher: greater_than"
+        );
+    }
+}
+
+mod assert_returns {
+    use super::*;
+
+    #[test]
+    fn should_assert_the_returned_value() {
+        assert_that!("42".parse::<i32>().unwrap(), returns eq(42));
+        assert_that!(vec![1, 2, 3], returns eq(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn should_fail_if_returned_value_does_not_match() {
+        assert_that!(
+            assert_that!(1 + 1, returns eq(3)),
+            panics with message "Failed assertion of matcher: equal\n  Expected: 3\n  Got: 2"
+        );
+    }
+
+    #[test]
+    fn should_fail_if_expression_panics() {
+        assert_that!(
+            assert_that!("x".parse::<i32>().unwrap(), returns eq(42)),
+            panics with message "expression panicked unexpectantly"
+        );
+    }
+}
+
+mod assert_expression {
+    use super::*;
+
+    #[test]
+    fn should_assert_an_expression_to_be_true() {
+        let ok: Result<i32,i32> = Ok(4);
+        assert_that!(ok.is_ok());
+    }
+
+    #[test]
+    fn should_fail_to_assert_an_expression_to_be_true() {
+        let err: Result<i32,i32> = Err(4);
+        assert_that!(
+            assert_that!(err.is_ok()),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_assert_an_expression_to_be_true_with_reason() {
+        let ok: Result<i32,i32> = Ok(4);
+        assert_that!(ok.is_ok(), otherwise "ok is Err");
+    }
+
+    #[test]
+    fn should_fail_to_assert_an_expression_to_be_true_with_reason() {
+        let err: Result<i32,i32> = Err(4);
+        assert_that!(
+            assert_that!(err.is_ok(), otherwise "err is Ok"),
+            panics
+        );
+    }
+}
+
+mod assert_with_message {
+    use super::*;
+
+    #[test]
+    fn should_assert_a_matcher_with_message() {
+        assert_that!(&1, eq(1), "custom message");
+    }
+
+    #[test]
+    #[should_panic(expected = "failed in iteration 2")]
+    fn should_fail_with_formatted_message() {
+        for i in 0..3 {
+            assert_that!(&i, lt(2), "failed in iteration {}", i);
+        }
+    }
+}
+
+mod try_assert_that {
+    use super::*;
+    use std::error::Error;
+
+    fn parse_and_check(input: &str) -> Result<i32, Box<dyn Error>> {
+        let value: i32 = input.parse()?;
+        try_assert_that!(&value, lt(10))?;
+        try_assert_that!(&value, gt(0), "parsed from {:?}", input)?;
+        Ok(value)
+    }
+
+    #[test]
+    fn should_return_ok_if_matched() -> Result<(), Box<dyn Error>> {
+        try_assert_that!(&1, eq(1))?;
+        assert_eq!(parse_and_check("5")?, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_formatted_failure() {
+        assert_eq!(try_assert_that!(&11, lt(10)), Err("Failed assertion of matcher: less_than\n  Bound: 10\n  Got: 11".to_owned()));
+        assert_eq!(parse_and_check("-1").unwrap_err().to_string(),
+                   "Failed assertion of matcher: greater_than\n  Bound: 0\n  Got: -1\n  Message: parsed from \"-1\"");
+    }
+}
+
+mod expect {
+    use super::*;
+    use galvanic_assert::matchers::variant::is_ok;
+
+    #[test]
+    fn should_return_the_asserted_value() {
+        let parsed = expect!("42".parse::<i32>(), is_ok());
+        assert_eq!(parsed, Ok(42));
+    }
+
+    #[test]
+    fn should_return_ownership_of_the_asserted_value() {
+        let name = expect!("galvanic".to_owned(), eq("galvanic".to_owned()));
+        assert_eq!(name, "galvanic");
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            expect!(1, eq(2)),
+            panics with message "Failed assertion of matcher: equal"
+        );
+    }
+}
+
+mod assert_all {
+    use super::*;
+
+    #[test]
+    fn should_pass_if_all_assertions_match() {
+        assert_all!(
+            (&1, eq(1)),
+            (&2, lt(3)),
+        );
+    }
+
+    #[test]
+    fn should_report_the_number_of_failed_assertions() {
+        assert_that!(
+            assert_all!((&1, eq(1)), (&2, lt(2)), (&3, gt(4))),
+            panics with message "2 of 3 assertions failed"
+        );
+    }
+
+    #[test]
+    fn should_report_every_failure() {
+        assert_that!(
+            assert_all!((&1, eq(2)), (&2, lt(2))),
+            panics with message "Assertion 1: '&1' matches 'eq(2)'\nFailed assertion of matcher: equal"
+        );
+        assert_that!(
+            assert_all!((&1, eq(2)), (&2, lt(2))),
+            panics with message "Assertion 2: '&2' matches 'lt(2)'\nFailed assertion of matcher: less_than"
+        );
+    }
+}
+
+mod assert_panics_for {
+    use super::*;
+
+    #[test]
+    fn should_assert_panics_and_regular_returns() {
+        assert_panics_for!(|divisor: i32| 10 / divisor, panics for [0], does not panic for [1, 2, -5]);
+        assert_panics_for!(|v: Vec<i32>| v[2], panics for [vec![], vec![1, 2]]);
+    }
+
+    #[test]
+    fn should_report_all_violating_inputs() {
+        assert_that!(
+            assert_panics_for!(|divisor: i32| 10 / divisor, panics for [0, 1], does not panic for [0, 2]),
+            panics with message "2 of 4 inputs violated their expectation\n  '1' did not panic\n  '0' panicked unexpectantly"
+        );
+    }
+}
+
+mod eventually {
+    use super::*;
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    #[test]
+    fn should_pass_once_the_produced_value_matches() {
+        let counter = Cell::new(0);
+        eventually!(Duration::from_secs(5), Duration::from_millis(1), || {
+            counter.set(counter.get() + 1);
+            counter.get()
+        }, eq(3));
+        assert_eq!(counter.get(), 3);
+    }
+
+    #[test]
+    fn should_fail_with_last_failure_after_timeout() {
+        assert_that!(
+            eventually!(Duration::from_millis(20), Duration::from_millis(5), || 1, eq(2)),
+            panics with message "no produced value matched within 20ms"
+        );
+    }
+}
+
+mod for_samples {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn should_check_every_sample() {
+        let counter = Cell::new(0);
+        for_samples!(5, || { counter.set(counter.get() + 1); counter.get() * 2 }, lt(11));
+        assert_eq!(counter.get(), 5);
+    }
+
+    #[test]
+    fn should_fail_with_first_failing_sample() {
+        assert_that!(
+            {
+                let counter = Cell::new(0);
+                for_samples!(10, || { counter.set(counter.get() + 1); counter.get() * 2 }, lt(7))
+            },
+            panics with message "sample 8 produced in iteration 3 of 10 does not match\nFailed assertion of matcher: less_than"
+        );
+    }
+}
+
+mod check_that {
+    use super::*;
+    use galvanic_assert::{check_that, MatchResult};
+
+    #[test]
+    fn should_return_matched_result() {
+        match check_that(&1, eq(1)) {
+            MatchResult::Matched { name } => assert_eq!(name, "equal"),
+            MatchResult::Failed { .. } => panic!("expected the matcher to match")
+        }
+    }
+
+    #[test]
+    fn should_return_failed_result_without_panicking() {
+        match check_that(&1, eq(2)) {
+            MatchResult::Matched { .. } => panic!("expected the matcher to fail"),
+            MatchResult::Failed { name, .. } => assert_eq!(name, "equal")
+        }
+    }
+
+    #[test]
+    fn should_accept_closure_matchers() {
+        let result = check_that(&1, |x: &i32| {
+            let builder = galvanic_assert::MatchResultBuilder::for_("odd");
+            if x % 2 == 1 { builder.matched() } else { builder.failed_because("not odd") }
+        });
+        assert_that!(result.is_matched());
+    }
+
+    #[test]
+    fn should_accept_closures_using_shortcuts() {
+        use galvanic_assert::{failed, matched};
+        let even = |x: &i32| if x % 2 == 0 { matched("even") } else { failed("even", "the value is odd") };
+        assert_eq!(check_that(&2, even).name(), "even");
+        assert_eq!(check_that(&1, even).reason(), Some("  Because: the value is odd"));
+    }
+}
+
+mod match_result {
+    use super::*;
+    use galvanic_assert::check_that;
+
+    #[test]
+    fn should_expose_matched_result() {
+        let result = check_that(&1, eq(1));
+        assert_that!(result.is_matched());
+        assert_that!(!result.is_failed());
+        assert_eq!(result.name(), "equal");
+        assert_eq!(result.reason(), None);
+    }
+
+    #[test]
+    fn should_expose_failed_result() {
+        let result = check_that(&1, eq(2));
+        assert_that!(result.is_failed());
+        assert_that!(!result.is_matched());
+        assert_eq!(result.name(), "equal");
+        assert_eq!(result.reason(), Some("  Expected: 2\n  Got: 1"));
+    }
+
+    #[test]
+    fn should_build_labeled_comparison() {
+        let result = galvanic_assert::MatchResultBuilder::for_("within").failed_comparison_labeled(&5, "Value", &3, "Limit");
+        assert_eq!(result.reason(), Some("  Limit: 3\n  Value: 5"));
+    }
+
+    #[test]
+    fn should_chain_results_with_and_then() {
+        assert_eq!(check_that(&1, eq(1)).and_then(|| check_that(&1, lt(2))).name(), "less_than");
+        assert_eq!(check_that(&1, eq(2)).and_then(|| check_that(&1, lt(2))).name(), "equal");
+        assert_that!(check_that(&1, eq(1)).and_then(|| check_that(&1, lt(0))).is_failed());
+    }
+
+    #[test]
+    fn should_chain_results_with_or_else() {
+        assert_eq!(check_that(&1, eq(1)).or_else(|| check_that(&1, lt(2))).name(), "equal");
+        assert_eq!(check_that(&1, eq(2)).or_else(|| check_that(&1, lt(2))).name(), "less_than");
+        assert_that!(check_that(&1, eq(2)).or_else(|| check_that(&1, lt(0))).is_failed());
+    }
+
+    #[test]
+    fn should_embed_nested_failure() {
+        let result = galvanic_assert::MatchResultBuilder::for_("outer").failed_because_of("inner", check_that(&1, eq(2)));
+        assert_eq!(result.name(), "outer");
+        assert_eq!(result.reason(), Some("  Because: inner does not match equal:\n    Expected: 2\n    Got: 1"));
+    }
+
+    #[test]
+    fn should_report_nested_match_as_failure() {
+        let result = galvanic_assert::MatchResultBuilder::for_("outer").failed_because_of("inner", check_that(&1, eq(1)));
+        assert_eq!(result.reason(), Some("  Because: inner unexpectedly matches equal"));
+    }
+
+    fn check_both(x: &i32) -> Result<(), String> {
+        Result::from(check_that(x, gt(0)))?;
+        Result::from(check_that(x, lt(10)))?;
+        Ok(())
+    }
+
+    #[test]
+    fn should_convert_into_result() {
+        assert_eq!(check_both(&5), Ok(()));
+        assert_eq!(check_both(&11), Err("Failed assertion of matcher: less_than\n  Bound: 10\n  Got: 11".to_owned()));
+    }
+
+    #[test]
+    fn should_display_matched_result_as_name() {
+        assert_eq!(check_that(&1, eq(1)).to_string(), "equal");
+    }
+
+    #[test]
+    fn should_display_failed_result_as_failure_message() {
+        assert_eq!(check_that(&1, eq(2)).to_string(),
+                   "Failed assertion of matcher: equal\n  Expected: 2\n  Got: 1");
+    }
+}
+
+mod invariants {
+    use super::*;
+
+    #[test]
+    fn assertion_should_succeed() {
+        assert_that!(&1, assertion_always_succeeds());
+    }
+
+    #[test]
+    fn assertion_should_fail() {
+        assert_that!(
+            assert_that!(&1, assertion_always_fails()),
+            panics
+        );
+    }
+}
+
+mod matcher_trait {
+    use super::*;
+    use galvanic_assert::Matcher;
+
+    #[test]
+    fn should_check_the_same_matcher_repeatedly() {
+        let matcher = less_than(3);
+        assert_that!(matcher.check(&1).is_matched());
+        assert_that!(matcher.check(&3).is_failed());
+    }
+}
+
+mod satisfies {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&4, satisfies("is even", |x: &i32| x % 2 == 0));
+    }
+
+    #[test]
+    fn should_fail_with_description() {
+        assert_that!(
+            assert_that!(&3, satisfies("is even", |x: &i32| x % 2 == 0)),
+            panics with message "value did not satisfy: is even"
+        );
+    }
+}
+
+mod not {
+    use super::*;
+
+    #[test]
+    fn should_invert_success() {
+        assert_that!(&1, not(assertion_always_fails()));
+    }
+
+    #[test]
+    fn should_invert_fail() {
+        assert_that!(
+            assert_that!(&1, not(assertion_always_succeeds())),
+            panics
+        );
+    }
+}
+
+mod not_macro {
+    use super::*;
+    use galvanic_assert::MatchResultBuilder;
+
+    #[test]
+    fn should_invert_boxed_matcher() {
+        assert_that!(&1, not!(equal_to(5)));
+    }
+
+    #[test]
+    fn should_invert_closure() {
+        assert_that!(&1, not!(|_: &i32| MatchResultBuilder::for_("even").failed_because("odd")));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&5, not!(equal_to(5))),
+            panics with message "equal is satisfied"
+        );
+    }
+}
+
+mod named {
+    use super::*;
+    use galvanic_assert::check_that;
+
+    #[test]
+    fn should_rename_matched_result() {
+        assert_eq!(check_that(&1, named("positive", gt(0))).name(), "positive");
+    }
+
+    #[test]
+    fn should_rename_failed_result_and_keep_reason() {
+        assert_that!(
+            assert_that!(&-1, named("positive", gt(0))),
+            panics with message "Failed assertion of matcher: positive\n  Bound: 0\n  Got: -1"
+        );
+    }
+}
+
+mod when {
+    use super::*;
+
+    #[test]
+    fn should_match_vacuously_if_guard_fails() {
+        assert_that!(&-200, when(|x: &i32| *x >= 0, less_than(100)));
+    }
+
+    #[test]
+    fn should_match_if_guard_holds() {
+        assert_that!(&50, when(|x: &i32| *x >= 0, less_than(100)));
+    }
+
+    #[test]
+    fn should_fail_if_guard_holds() {
+        assert_that!(
+            assert_that!(&200, when(|x: &i32| *x >= 0, less_than(100))),
+            panics with message "Failed assertion of matcher: when(less_than)"
+        );
+    }
+}
+
+mod locked_value {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    #[test]
+    fn should_match_guarded_value() {
+        let counter = Arc::new(Mutex::new(0));
+        let handles: Vec<_> = (0..4).map(|_| {
+            let counter = counter.clone();
+            thread::spawn(move || *counter.lock().unwrap() += 1)
+        }).collect();
+        for handle in handles { handle.join().unwrap(); }
+        assert_that!(&*counter, locked_value(|value: &i32| eq(4).check(value)));
+    }
+
+    #[test]
+    fn should_release_lock_on_failure() {
+        let mutex = Mutex::new(1);
+        let result = galvanic_assert::check_that(&mutex, locked_value(|value: &i32| eq(2).check(value)));
+        assert_eq!(result.name(), "locked_value(equal)");
+        assert_that!(mutex.try_lock().is_ok());
+    }
+
+    #[test]
+    fn should_fail_if_poisoned() {
+        let mutex = Arc::new(Mutex::new(1));
+        let poisoner = mutex.clone();
+        let _ = thread::spawn(move || {
+            let _guard = poisoner.lock().unwrap();
+            panic!("poison the mutex");
+        }).join();
+        let result = galvanic_assert::check_that(&*mutex, locked_value(|value: &i32| eq(1).check(value)));
+        assert_eq!(result.reason(), Some("  Because: the mutex is poisoned by a thread which panicked while holding the lock"));
+    }
+}
+
+mod mapped {
+    use super::*;
+
+    #[test]
+    fn should_match_transformed_value() {
+        assert_that!(&"  galvanic  ", mapped(|s: &&str| s.trim().to_owned(),
+                                             |trimmed: &String| eq("galvanic".to_owned()).check(trimmed)));
+    }
+
+    #[test]
+    fn should_fail_and_mention_transformation() {
+        assert_that!(
+            assert_that!(&-3, mapped(|x: &i32| x.abs(), |abs: &i32| lt(2).check(abs))),
+            panics with message "Failed assertion of matcher: mapped(less_than)\n  Because: the transformed value does not match less_than:\n    Bound: 2\n    Got: 3"
+        );
+    }
+}
+
+mod is_type {
+    use super::*;
+    use std::any::Any;
+
+    #[test]
+    fn should_match_value_of_type() {
+        let event: Box<dyn Any> = Box::new(42);
+        assert_that!(&event, is_type::<i32>(eq(42)));
+    }
+
+    #[test]
+    fn should_fail_if_nested_matcher_fails() {
+        let event: Box<dyn Any> = Box::new(42);
+        let result = galvanic_assert::check_that(&event, is_type::<i32>(eq(1)));
+        assert_eq!(result.name(), "is_type(equal)");
+        assert_eq!(result.reason(), Some("  Expected: 1\n  Got: 42"));
+    }
+
+    #[test]
+    fn should_fail_for_other_type() {
+        let event: Box<dyn Any> = Box::new("event");
+        let result = galvanic_assert::check_that(&event, is_type::<i32>(eq(42)));
+        assert_eq!(result.reason(), Some("  Because: value is not of type i32"));
+    }
+}
+
+mod deref_to {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&Box::new(5), deref_to(equal_to(5)));
+        assert_that!(&Rc::new(5), deref_to(less_than(6)));
+    }
+
+    #[test]
+    fn should_fail_with_nested_reason() {
+        assert_that!(
+            assert_that!(&Box::new(5), deref_to(equal_to(4))),
+            panics with message "Failed assertion of matcher: deref_to(equal)\n  Expected: 4\n  Got: 5"
+        );
+    }
+}
+
+mod eq {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1, eq(1));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&1, eq(2)), panics
+        );
+    }
+}
+
+mod less_than {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1, less_than(2));
+        assert_that!(&1, lt(2));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&1, less_than(1)),
+            panics
+        );
+        assert_that!(
+            assert_that!(&1, lt(1)),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_report_bound() {
+        assert_that!(
+            assert_that!(&1, less_than(1)),
+            panics with message "Failed assertion of matcher: less_than\n  Bound: 1\n  Got: 1"
+        );
+    }
 }
 
-mod assert_does_not_panic {
+mod greater_than {
     use super::*;
 
     #[test]
-    fn should_assert_that_no_panic_occurred() {
-        assert_that!(&1+1, does not panic);
+    fn should_match() {
+        assert_that!(&1, greater_than(0));
+        assert_that!(&1, gt(0));
     }
 
     #[test]
-    fn should_fail_to_assert_a_panic() {
-        let panicked = std::panic::catch_unwind(|| {
-            assert_that!(panic!("panic"), does not panic);
-        });
-        assert!(panicked.is_err());
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&1, greater_than(1)),
+            panics
+        );
+        assert_that!(
+            assert_that!(&1, gt(1)),
+            panics
+        );
     }
 }
 
-mod assert_expression {
+mod less_than_or_equal {
     use super::*;
 
     #[test]
-    fn should_assert_an_expression_to_be_true() {
-        let ok: Result<i32,i32> = Ok(4);
-        assert_that!(ok.is_ok());
+    fn should_match() {
+        assert_that!(&1, less_than_or_equal(2));
+        assert_that!(&1, less_than_or_equal(1));
+        assert_that!(&1, leq(2));
     }
 
     #[test]
-    fn should_fail_to_assert_an_expression_to_be_true() {
-        let err: Result<i32,i32> = Err(4);
+    fn should_fail() {
         assert_that!(
-            assert_that!(err.is_ok()),
+            assert_that!(&1, less_than_or_equal(0)),
+            panics
+        );
+        assert_that!(
+            assert_that!(&1, leq(0)),
             panics
         );
     }
+}
+
+mod greater_than_or_equal {
+    use super::*;
 
     #[test]
-    fn should_assert_an_expression_to_be_true_with_reason() {
-        let ok: Result<i32,i32> = Ok(4);
-        assert_that!(ok.is_ok(), otherwise "ok is Err");
+    fn should_match() {
+        assert_that!(&1, greater_than_or_equal(0));
+        assert_that!(&1, greater_than_or_equal(1));
+        assert_that!(&1, geq(0));
     }
 
     #[test]
-    fn should_fail_to_assert_an_expression_to_be_true_with_reason() {
-        let err: Result<i32,i32> = Err(4);
+    fn should_fail() {
         assert_that!(
-            assert_that!(err.is_ok(), otherwise "err is Ok"),
+            assert_that!(&1, greater_than_or_equal(2)),
+            panics
+        );
+        assert_that!(
+            assert_that!(&1, geq(2)),
             panics
         );
     }
 }
 
-mod invariants {
+mod is_default {
     use super::*;
 
     #[test]
-    fn assertion_should_succeed() {
-        assert_that!(&1, assertion_always_succeeds());
+    fn should_match() {
+        assert_that!(&0, is_default());
+        assert_that!(&String::new(), is_default());
+        assert_that!(&Vec::<i32>::new(), is_default());
     }
 
     #[test]
-    fn assertion_should_fail() {
+    fn should_fail_with_default_and_actual() {
         assert_that!(
-            assert_that!(&1, assertion_always_fails()),
-            panics
+            assert_that!(&"galvanic".to_owned(), is_default()),
+            panics with message "Failed assertion of matcher: is_default\n  Default: \"\"\n  Got: \"galvanic\""
         );
     }
 }
 
-mod not {
+mod compares_as {
     use super::*;
+    use std::cmp::Ordering;
 
     #[test]
-    fn should_invert_success() {
-        assert_that!(&1, not(assertion_always_fails()));
+    fn should_match() {
+        assert_that!(&"apple", compares_as(Ordering::Less, "banana"));
+        assert_that!(&2, compares_as(Ordering::Equal, 2));
+        assert_that!(&3, compares_as(Ordering::Greater, 2));
     }
 
     #[test]
-    fn should_invert_fail() {
+    fn should_fail_with_actual_ordering() {
         assert_that!(
-            assert_that!(&1, not(assertion_always_succeeds())),
-            panics
+            assert_that!(&3, compares_as(Ordering::Less, 2)),
+            panics with message "3 compares as Greater to 2 instead of Less"
         );
     }
 }
 
-mod eq {
+mod is_multiple_of {
     use super::*;
 
     #[test]
     fn should_match() {
-        assert_that!(&1, eq(1));
+        assert_that!(&24usize, is_multiple_of(8));
+        assert_that!(&0, is_multiple_of(3));
+        assert_that!(&-9, is_multiple_of(3));
+        assert_that!(&7.5, is_multiple_of(2.5));
+    }
+
+    #[test]
+    fn should_fail_with_remainder() {
+        assert_that!(
+            assert_that!(&26, is_multiple_of(8)),
+            panics with message "26 is not a multiple of 8, the remainder is 2"
+        );
+    }
+
+    #[test]
+    fn should_not_overflow_for_minus_one() {
+        assert_that!(&i32::MIN, is_multiple_of(-1));
+        assert_that!(&i64::MIN, is_multiple_of(-1));
+    }
+
+    #[test]
+    fn should_reject_zero_divisor() {
+        assert_that!(is_multiple_of::<i32>(0), panics with message "is_multiple_of requires a non-zero divisor");
+    }
+}
+
+mod sign {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1, is_positive());
+        assert_that!(&0.5, is_positive());
+        assert_that!(&-1, is_negative());
+        assert_that!(&-0.5, is_negative());
+        assert_that!(&0, is_zero());
+        assert_that!(&-0.0, is_zero());
+    }
+
+    #[test]
+    fn should_fail_with_expected_sign() {
+        assert_that!(assert_that!(&0, is_positive()), panics with message "0 is not positive");
+        assert_that!(assert_that!(&0, is_negative()), panics with message "0 is not negative");
+        assert_that!(assert_that!(&-2, is_zero()), panics with message "-2 is not zero");
+        assert_that!(assert_that!(&f64::NAN, is_positive()), panics with message "NaN is not positive");
+    }
+}
+
+mod is_true {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&true, is_true());
+        assert_that!(&false, not(is_true()));
     }
 
     #[test]
     fn should_fail() {
         assert_that!(
-            assert_that!(&1, eq(2)), panics
+            assert_that!(&false, is_true()),
+            panics with message "Expected: true\n  Got: false"
         );
     }
 }
 
-mod less_than {
+mod is_false {
     use super::*;
 
     #[test]
     fn should_match() {
-        assert_that!(&1, less_than(2));
-        assert_that!(&1, lt(2));
+        assert_that!(&false, is_false());
+        assert_that!(&true, not(is_false()));
     }
 
     #[test]
     fn should_fail() {
         assert_that!(
-            assert_that!(&1, less_than(1)),
-            panics
+            assert_that!(&true, is_false()),
+            panics with message "Expected: false\n  Got: true"
         );
+    }
+}
+
+mod is_one_of {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"b", is_one_of(vec!["a", "b", "c"]));
+    }
+
+    #[test]
+    fn should_fail() {
         assert_that!(
-            assert_that!(&1, lt(1)),
-            panics
+            assert_that!(&4, is_one_of(vec![1, 2, 3])),
+            panics with message "4 is not one of [1, 2, 3]"
         );
     }
 }
 
-mod greater_than {
+mod between {
     use super::*;
 
     #[test]
     fn should_match() {
-        assert_that!(&1, greater_than(0));
-        assert_that!(&1, gt(0));
+        assert_that!(&1, between(1, 3));
+        assert_that!(&2, between(1, 3));
+        assert_that!(&3, between(1, 3));
     }
 
     #[test]
     fn should_fail() {
         assert_that!(
-            assert_that!(&1, greater_than(1)),
-            panics
+            assert_that!(&4, between(1, 3)),
+            panics with message "4 is not within [1, 3]"
+        );
+    }
+}
+
+mod between_exclusive {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&2, between_exclusive(1, 3));
+    }
+
+    #[test]
+    fn should_fail_on_endpoints() {
+        assert_that!(
+            assert_that!(&1, between_exclusive(1, 3)),
+            panics with message "1 is not within (1, 3)"
         );
         assert_that!(
-            assert_that!(&1, gt(1)),
+            assert_that!(&3, between_exclusive(1, 3)),
             panics
         );
     }
 }
 
-mod less_than_or_equal {
+#[allow(clippy::approx_constant)]
+mod close_to {
     use super::*;
 
     #[test]
     fn should_match() {
-        assert_that!(&1, less_than_or_equal(2));
-        assert_that!(&1, less_than_or_equal(1));
-        assert_that!(&1, leq(2));
+        assert_that!(&3.14, close_to(3.14, 0.001));
     }
 
     #[test]
     fn should_fail() {
         assert_that!(
-            assert_that!(&1, less_than_or_equal(0)),
+            assert_that!(&2.2, close_to(3.14, 0.001)),
             panics
         );
+    }
+
+    #[derive(Clone, Debug, PartialEq, PartialOrd)]
+    struct Decimal(Vec<i64>);
+
+    impl std::ops::Add for Decimal {
+        type Output = Decimal;
+        fn add(self, other: Decimal) -> Decimal { Decimal(vec![self.0[0] + other.0[0]]) }
+    }
+
+    impl std::ops::Sub for Decimal {
+        type Output = Decimal;
+        fn sub(self, other: Decimal) -> Decimal { Decimal(vec![self.0[0] - other.0[0]]) }
+    }
+
+    #[test]
+    fn should_match_non_copy_values() {
+        assert_that!(&Decimal(vec![101]), close_to(Decimal(vec![100]), Decimal(vec![2])));
+    }
+
+    #[test]
+    fn should_fail_non_copy_values() {
+        assert_that!(
+            assert_that!(&Decimal(vec![103]), close_to(Decimal(vec![100]), Decimal(vec![2]))),
+            panics with message "Decimal([103]) should be between Decimal([98]) and Decimal([102])"
+        );
+    }
+}
+
+mod not_close_to {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&2.2, not_close_to(2.5, 0.001));
+        assert_that!(&f64::NAN, not_close_to(2.5, 0.001));
+    }
+
+    #[test]
+    fn should_fail_with_epsilon_range() {
         assert_that!(
-            assert_that!(&1, leq(0)),
+            assert_that!(&2.5, not_close_to(2.5, 0.5)),
+            panics with message "2.5 is unexpectedly between 2.0 and 3.0"
+        );
+    }
+}
+
+mod close_to_relative {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1.0001e10, close_to_relative(1.0e10, 0.001));
+    }
+
+    #[test]
+    fn should_match_negative_value() {
+        assert_that!(&-1.0001e10, close_to_relative(-1.0e10, 0.001));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&1.1e10, close_to_relative(1.0e10, 0.001)),
             panics
         );
     }
 }
 
-mod greater_than_or_equal {
+mod is_nan {
     use super::*;
 
     #[test]
     fn should_match() {
-        assert_that!(&1, greater_than_or_equal(0));
-        assert_that!(&1, greater_than_or_equal(1));
-        assert_that!(&1, geq(0));
+        assert_that!(&f64::NAN, is_nan());
+        assert_that!(&f32::NAN, is_nan());
     }
 
     #[test]
     fn should_fail() {
         assert_that!(
-            assert_that!(&1, greater_than_or_equal(2)),
+            assert_that!(&f64::INFINITY, is_nan()),
             panics
         );
+    }
+}
+
+#[allow(clippy::approx_constant)]
+mod is_finite {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&3.14, is_finite());
+    }
+
+    #[test]
+    fn should_fail() {
         assert_that!(
-            assert_that!(&1, geq(2)),
+            assert_that!(&f64::NAN, is_finite()),
             panics
         );
     }
 }
 
-mod close_to {
+#[allow(clippy::approx_constant)]
+mod is_infinite {
     use super::*;
 
     #[test]
     fn should_match() {
-        assert_that!(&3.14, close_to(3.14, 0.001));
+        assert_that!(&f64::NEG_INFINITY, is_infinite());
     }
 
     #[test]
     fn should_fail() {
         assert_that!(
-            assert_that!(&2.2, close_to(3.14, 0.001)),
+            assert_that!(&3.14f32, is_infinite()),
             panics
         );
     }
 }
 
+mod within_ulps {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&(0.1 + 0.2), within_ulps(0.3, 1));
+        assert_that!(&1.0, within_ulps(1.0, 0));
+        assert_that!(&-0.0, within_ulps(0.0, 0));
+        assert_that!(&f64::from_bits(1), within_ulps(-f64::from_bits(1), 2));
+    }
+
+    #[test]
+    fn should_fail_with_distance() {
+        assert_that!(
+            assert_that!(&f64::from_bits(1), within_ulps(-f64::from_bits(1), 1)),
+            panics with message "5e-324 is 2 ULPs away from -5e-324 but at most 1 are allowed"
+        );
+    }
+
+    #[test]
+    fn should_fail_on_nan() {
+        assert_that!(
+            assert_that!(&f64::NAN, within_ulps(f64::NAN, 10)),
+            panics with message "NaN and NaN cannot be compared as one of them is NaN"
+        );
+    }
+}
+
+mod bit_equal_to {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&1.5, bit_equal_to(1.5));
+        assert_that!(&f64::NAN, bit_equal_to(f64::NAN));
+        assert_that!(&0.5f32, bit_equal_to_f32(0.5));
+    }
+
+    #[test]
+    fn should_fail_on_sign_of_zero() {
+        assert_that!(
+            assert_that!(&-0.0, bit_equal_to(0.0)),
+            panics with message "Expected: 0.0 (0x0000000000000000)\n  Got: -0.0 (0x8000000000000000)"
+        );
+        assert_that!(
+            assert_that!(&-0.0f32, bit_equal_to_f32(0.0)),
+            panics with message "Expected: 0.0 (0x00000000)\n  Got: -0.0 (0x80000000)"
+        );
+    }
+}
+
 mod same_object {
     use super::*;
 
@@ -267,3 +1247,24 @@ mod same_object {
         );
     }
 }
+
+mod points_into {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        let buffer = vec![1, 2, 3, 4];
+        let tail = &buffer[2..];
+        let all = &buffer[..];
+        assert_that!(&tail, points_into(&buffer));
+        assert_that!(&all, points_into(&buffer));
+    }
+
+    #[test]
+    fn should_fail_with_pointer_ranges() {
+        let buffer = vec![1, 2, 3, 4];
+        let copy = buffer.clone();
+        let result = galvanic_assert::check_that(&&copy[..], points_into(&buffer));
+        assert_that!(result.reason().unwrap().contains("does not lie within the buffer at"));
+    }
+}
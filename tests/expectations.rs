@@ -145,7 +145,6 @@ mod expect_that {
         #[test]
         #[should_panic]
         fn should_be_failing_to_expect_an_expression_to_be_true() {
-            #[allow(unused_variables)]
             expect_that!(1 != 1);
         }
 
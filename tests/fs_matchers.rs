@@ -0,0 +1,76 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::fs::*;
+use std::path::PathBuf;
+
+fn in_crate(relative: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(relative)
+}
+
+mod path_exists {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&in_crate("Cargo.toml"), path_exists());
+        assert_that!(&in_crate("src"), path_exists());
+    }
+
+    #[test]
+    fn should_fail_with_io_error() {
+        assert_that!(
+            assert_that!(&in_crate("does-not-exist"), path_exists()),
+            panics with message "does-not-exist\" cannot be accessed: "
+        );
+    }
+}
+
+mod is_file {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&in_crate("Cargo.toml"), is_file());
+    }
+
+    #[test]
+    fn should_fail_with_actual_file_type() {
+        assert_that!(
+            assert_that!(&in_crate("src"), is_file()),
+            panics with message "src\" is a directory"
+        );
+    }
+}
+
+mod is_dir {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&in_crate("src"), is_dir());
+    }
+
+    #[test]
+    fn should_fail_with_actual_file_type() {
+        assert_that!(
+            assert_that!(&in_crate("Cargo.toml"), is_dir()),
+            panics with message "Cargo.toml\" is a file"
+        );
+    }
+}
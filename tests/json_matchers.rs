@@ -0,0 +1,51 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![cfg(feature = "json")]
+
+#[macro_use]
+extern crate galvanic_assert;
+#[macro_use]
+extern crate serde_json;
+
+use galvanic_assert::matchers::*;
+use galvanic_assert::matchers::json::*;
+
+mod json_has_field {
+    use super::*;
+
+    #[test]
+    fn should_match_nested_field() {
+        let response = json!({ "user": { "name": "Alice", "roles": ["admin"] } });
+        assert_that!(&response, json_has_field("user.name", eq(json!("Alice"))));
+        assert_that!(&response, json_has_field("user.roles.0", eq(json!("admin"))));
+    }
+
+    #[test]
+    fn should_fail_if_path_cannot_be_resolved() {
+        assert_that!(
+            assert_that!(&json!({ "user": { "name": "Alice" } }), json_has_field("user.age.years", assertion_always_succeeds())),
+            panics with message "path \"user.age\" cannot be resolved"
+        );
+    }
+
+    #[test]
+    fn should_fail_if_nested_matcher_fails() {
+        assert_that!(
+            assert_that!(&json!({ "user": { "name": "Alice" } }), json_has_field("user.name", eq(json!("Bob")))),
+            panics with message "user.name does not match equal:\n    Expected: String(\"Bob\")"
+        );
+    }
+}
@@ -0,0 +1,46 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::set::*;
+use std::collections::HashSet;
+
+mod set_equal_to {
+    use super::*;
+
+    #[test]
+    fn should_match_set() {
+        let actual: HashSet<i32> = vec![1,2,3].into_iter().collect();
+        let expected: HashSet<i32> = vec![3,2,1].into_iter().collect();
+        assert_that!(&actual, set_equal_to(expected));
+    }
+
+    #[test]
+    fn should_match_vec() {
+        let actual: HashSet<i32> = vec![1,2,3].into_iter().collect();
+        assert_that!(&actual, set_equal_to(vec![3,2,1,1]));
+    }
+
+    #[test]
+    fn should_fail_with_differences() {
+        let actual: HashSet<i32> = vec![1,2,4].into_iter().collect();
+        assert_that!(
+            assert_that!(&actual, set_equal_to(vec![1,2,3])),
+            panics with message "the sets differ\n\tOnly in expected: [3]\n\tOnly in actual: [4]"
+        );
+    }
+}
@@ -0,0 +1,257 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+use galvanic_assert::matchers::string::*;
+
+mod starts_with {
+    use super::*;
+
+    #[test]
+    fn should_match_str() {
+        assert_that!(&"Hello World", starts_with("Hello"));
+    }
+
+    #[test]
+    fn should_match_string() {
+        assert_that!(&"Hello World".to_string(), starts_with("Hello"));
+    }
+
+    #[test]
+    fn should_match_one_of_several_prefixes() {
+        assert_that!(&"error: out of memory", any_of!(starts_with("warning:"), starts_with("error:")));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"Hello World", starts_with("World")),
+            panics
+        );
+    }
+}
+
+mod ends_with {
+    use super::*;
+
+    #[test]
+    fn should_match_str() {
+        assert_that!(&"Hello World", ends_with("World"));
+    }
+
+    #[test]
+    fn should_match_string() {
+        assert_that!(&"Hello World".to_string(), ends_with("World"));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"Hello World", ends_with("Hello")),
+            panics
+        );
+    }
+}
+
+mod contains_substring {
+    use super::*;
+
+    #[test]
+    fn should_match_str() {
+        assert_that!(&"Hello World", contains_substring("lo Wo"));
+    }
+
+    #[test]
+    fn should_match_string() {
+        assert_that!(&"Hello World".to_string(), contains_substring("lo Wo"));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"Hello World", contains_substring("foo")),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_fail_for_long_string() {
+        let long_string = "x".repeat(1000);
+        assert_that!(
+            assert_that!(&long_string, contains_substring("foo")),
+            panics
+        );
+    }
+}
+
+mod contains_substring_ignoring_case {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"ERROR: connection refused", contains_substring_ignoring_case("Connection Refused"));
+        assert_that!(&"GRÜSSE".to_owned(), contains_substring_ignoring_case("rüs"));
+    }
+
+    #[test]
+    fn should_fail_with_original_strings() {
+        assert_that!(
+            assert_that!(&"ERROR: timeout", contains_substring_ignoring_case("Refused")),
+            panics with message "\"ERROR: timeout\" does not contain \"Refused\" ignoring case"
+        );
+    }
+}
+
+mod equal_to_ignoring_case {
+    use super::*;
+
+    #[test]
+    fn should_match_str() {
+        assert_that!(&"Hello World", equal_to_ignoring_case("hello WORLD"));
+    }
+
+    #[test]
+    fn should_match_unicode_string() {
+        assert_that!(&"ÄRGER".to_string(), equal_to_ignoring_case("ärger"));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"Hello World", equal_to_ignoring_case("hello")),
+            panics
+        );
+    }
+}
+
+mod has_char_count {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"grüße", has_char_count(5));
+        assert_that!(&String::new(), has_char_count(0));
+    }
+
+    #[test]
+    fn should_fail_and_report_bytes() {
+        assert_that!(
+            assert_that!(&"grüße", has_char_count(7)),
+            panics with message "\"grüße\" has 5 chars (7 bytes) instead of 7 chars"
+        );
+    }
+}
+
+mod parses_as {
+    use super::*;
+
+    #[test]
+    fn should_match_parsed_value() {
+        assert_that!("42", parses_as(|number: &i32| equal_to(42).check(number)));
+        assert_that!(&"42", parses_as(|number: &i32| equal_to(42).check(number)));
+        assert_that!(&"2.5".to_owned(), parses_as(|number: &f64| lt(3.0).check(number)));
+    }
+
+    #[test]
+    fn should_fail_if_parsed_value_does_not_match() {
+        assert_that!(
+            assert_that!(&"42", parses_as(|number: &i32| equal_to(43).check(number))),
+            panics with message "Failed assertion of matcher: parses_as(equal)\n  Because: the value parsed from \"42\" does not match equal:\n    Expected: 43\n    Got: 42"
+        );
+    }
+
+    #[test]
+    fn should_fail_with_parse_error() {
+        assert_that!(
+            assert_that!(&"forty-two", parses_as(|number: &i32| equal_to(42).check(number))),
+            panics with message "\"forty-two\" cannot be parsed: invalid digit found in string"
+        );
+    }
+}
+
+mod decodes_utf8_as {
+    use super::*;
+
+    #[test]
+    fn should_match_decoded_string() {
+        assert_that!(&"grüße".as_bytes().to_vec(), decodes_utf8_as(equal_to_ignoring_case("Grüße")));
+        assert_that!(&b"galvanic", decodes_utf8_as(starts_with("gal")));
+    }
+
+    #[test]
+    fn should_fail_if_decoded_string_does_not_match() {
+        assert_that!(
+            assert_that!(&b"abc".to_vec(), decodes_utf8_as(equal_to_ignoring_case("abd"))),
+            panics with message "the decoded string \"abc\" does not match equal_to_ignoring_case:\n    Expected: \"abd\"\n    Got: \"abc\""
+        );
+    }
+
+    #[test]
+    fn should_fail_with_offset_of_invalid_byte() {
+        assert_that!(
+            assert_that!(&vec![b'a', b'b', 0xff, b'c'], decodes_utf8_as(equal_to_ignoring_case("ab"))),
+            panics with message "invalid UTF-8 at byte offset 2: "
+        );
+    }
+}
+
+mod all_chars_satisfy {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&"abc123", all_chars_satisfy("is alphanumeric", |c| c.is_alphanumeric()));
+        assert_that!(&"".to_owned(), all_chars_satisfy("is alphanumeric", |c| c.is_alphanumeric()));
+    }
+
+    #[test]
+    fn should_fail_with_first_offending_char() {
+        assert_that!(
+            assert_that!(&"äb-c_", all_chars_satisfy("is alphanumeric", |c| c.is_alphanumeric())),
+            panics with message "'-' at byte index 3 of \"äb-c_\" does not satisfy: is alphanumeric"
+        );
+    }
+}
+
+#[cfg(feature = "regex")]
+mod matches_regex {
+    use super::*;
+
+    #[test]
+    fn should_match_str() {
+        assert_that!(&"2017-04-05", matches_regex(r"^\d{4}-\d{2}-\d{2}$"));
+    }
+
+    #[test]
+    fn should_match_string() {
+        assert_that!(&"level=error".to_string(), matches_regex(r"level=(warn|error)"));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&"2017/04/05", matches_regex(r"^\d{4}-\d{2}-\d{2}$")),
+            panics
+        );
+    }
+
+    #[test]
+    fn should_panic_on_construction_with_invalid_pattern() {
+        assert_that!(matches_regex::<&str>(r"(unclosed"), panics);
+    }
+}
@@ -0,0 +1,150 @@
+/* Copyright 20&17 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::*;
+
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32
+}
+
+#[derive(Debug)]
+struct User {
+    name: String,
+    age: u32
+}
+
+#[derive(Debug)]
+struct Line {
+    start: Point,
+    end: Point
+}
+
+mod structure {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&Point { x: 1, y: 2 }, structure!(Point { x: equal_to(1), y: greater_than(0) }));
+    }
+
+    #[test]
+    fn should_match_subset_of_fields() {
+        assert_that!(&Point { x: 1, y: 2 }, structure!(Point { y: eq(2), }));
+    }
+
+    #[test]
+    fn should_match_nested_structures() {
+        let line = Line { start: Point { x: 0, y: 0 }, end: Point { x: 1, y: 2 } };
+        assert_that!(&line, structure!(Line {
+            start: structure!(Point { x: eq(0), y: eq(0) }),
+            end: structure!(Point { x: eq(1), y: eq(2) })
+        }));
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&Point { x: 1, y: 2 }, structure!(Point { x: equal_to(1), y: less_than(0) })),
+            panics
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "x: equal")]
+    fn should_report_all_failing_fields() {
+        assert_that!(&Point { x: 1, y: 2 }, structure!(Point { x: equal_to(2), y: less_than(0) }));
+    }
+}
+
+mod field {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        let user = User { name: "Alice".to_owned(), age: 42 };
+        assert_that!(&user, field!(|u: &User| u.age, greater_than(18)));
+    }
+
+    #[test]
+    fn should_combine_with_all_of() {
+        let user = User { name: "Alice".to_owned(), age: 42 };
+        assert_that!(&user, all_of!(field!(|u: &User| u.age, greater_than(18)),
+                                    field!(|u: &User| u.name, eq("Alice".to_owned()))));
+    }
+
+    #[test]
+    fn should_fail_with_accessor_text() {
+        let user = User { name: "Bob".to_owned(), age: 12 };
+        assert_that!(
+            assert_that!(&user, field!(|u: &User| u.age, greater_than(18))),
+            panics with message "Failed assertion of matcher: field(u.age)\n  Because: u.age does not match greater_than:"
+        );
+    }
+}
+
+mod struct_matcher {
+    use super::*;
+    use galvanic_assert::matchers::structure::StructMatcher;
+
+    #[test]
+    fn should_match_without_fields() {
+        assert_that!(&Point { x: 1, y: 2 }, StructMatcher::new());
+    }
+
+    #[test]
+    fn should_match_fields_added_in_loop() {
+        let mut matcher = StructMatcher::new();
+        for &(name, bound) in &[("x", 5), ("y", 10)] {
+            let accessor: fn(&Point) -> &i32 = if name == "x" { |p| &p.x } else { |p| &p.y };
+            matcher = matcher.field(name, accessor, less_than(bound));
+        }
+        assert_that!(&Point { x: 1, y: 2 }, matcher);
+    }
+
+    #[test]
+    fn should_report_all_failing_fields() {
+        assert_that!(
+            assert_that!(&User { name: "Alice".to_owned(), age: 12 },
+                         StructMatcher::new().field("name", |u: &User| &u.name, eq("Bob".to_owned()))
+                                             .field("age", |u: &User| &u.age, greater_than(18))),
+            panics with message "the following fields did not match:\n    name: equal\n      Expected: \"Bob\"\n      Got: \"Alice\"\n    age: greater_than"
+        );
+    }
+}
+
+mod tuple {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&(1, 2), tuple!(equal_to(1), greater_than(0)));
+        assert_that!(&(1, "two", 3.0), tuple!(eq(1), eq("two"), lt(4.0)));
+        assert_that!(&(1, 2, 3, 4), tuple!(eq(1), eq(2), eq(3), eq(4)));
+        assert_that!(&(1, 2, 3, 4, 5), tuple!(eq(1), eq(2), eq(3), eq(4), gt(4)));
+    }
+
+    #[test]
+    fn should_report_all_failing_elements() {
+        assert_that!(
+            assert_that!(&(1, 2, 3), tuple!(eq(2), eq(2), lt(3))),
+            panics with message "the following elements did not match:\n    0: equal\n      Expected: 2\n      Got: 1\n    2: less_than\n      Bound: 3\n      Got: 3"
+        );
+    }
+}
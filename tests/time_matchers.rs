@@ -0,0 +1,53 @@
+/* Copyright 2017 Christopher Bacher
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[macro_use]
+extern crate galvanic_assert;
+
+use galvanic_assert::matchers::time::*;
+use std::time::Duration;
+
+mod duration_within {
+    use super::*;
+
+    #[test]
+    fn should_match() {
+        assert_that!(&Duration::from_millis(110), duration_within(Duration::from_millis(100), Duration::from_millis(20)));
+        assert_that!(&Duration::from_millis(80), duration_within(Duration::from_millis(100), Duration::from_millis(20)));
+    }
+
+    #[test]
+    fn should_match_if_tolerance_exceeds_expected() {
+        assert_that!(&Duration::from_millis(0), duration_within(Duration::from_millis(10), Duration::from_millis(20)));
+    }
+
+    #[test]
+    fn should_not_overflow_if_upper_bound_exceeds_max_duration() {
+        let max = Duration::new(u64::MAX, 999_999_999);
+        assert_that!(&max, duration_within(max, Duration::from_secs(1)));
+        assert_that!(
+            assert_that!(&Duration::from_secs(1), duration_within(max, Duration::from_secs(1))),
+            panics with message "i.e., at least"
+        );
+    }
+
+    #[test]
+    fn should_fail() {
+        assert_that!(
+            assert_that!(&Duration::from_millis(150), duration_within(Duration::from_millis(100), Duration::from_millis(20))),
+            panics with message "150ms is not within 20ms of 100ms, i.e., between 80ms and 120ms"
+        );
+    }
+}
@@ -40,6 +40,98 @@ mod is_variant {
     }
 }
 
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Event {
+    Click(i32),
+    Move(i32, i32),
+    Key { code: u32, shift: bool },
+    Quit
+}
+
+mod variant {
+    use super::*;
+    use galvanic_assert::matchers::*;
+
+    #[test]
+    fn should_succeed_for_tuple_variant() {
+        assert_that!(&Event::Click(2), variant!(Event::Click(equal_to(2))));
+        assert_that!(&Event::Move(1, 2), variant!(Event::Move(equal_to(1), greater_than(1))));
+    }
+
+    #[test]
+    fn should_succeed_for_struct_variant() {
+        assert_that!(&Event::Key { code: 13, shift: false }, variant!(Event::Key { code: equal_to(13) }));
+        assert_that!(&Event::Key { code: 13, shift: false },
+                     variant!(Event::Key { code: equal_to(13), shift: equal_to(false) }));
+    }
+
+    #[test]
+    fn should_succeed_for_unit_variant() {
+        assert_that!(&Event::Quit, variant!(Event::Quit));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected variant 'Event::Click' but got Quit")]
+    fn should_fail_due_to_wrong_variant() {
+        assert_that!(&Event::Quit, variant!(Event::Click(equal_to(2))));
+    }
+
+    #[test]
+    #[should_panic(expected = "1: less_than")]
+    fn should_fail_due_to_nonmatching_tuple_field() {
+        assert_that!(&Event::Move(1, 2), variant!(Event::Move(equal_to(1), less_than(1))));
+    }
+
+    #[test]
+    #[should_panic(expected = "code: equal")]
+    fn should_fail_due_to_nonmatching_struct_field() {
+        assert_that!(&Event::Key { code: 13, shift: false }, variant!(Event::Key { code: equal_to(10) }));
+    }
+}
+
+mod is_some {
+    use galvanic_assert::matchers::not;
+    use galvanic_assert::matchers::variant::is_some;
+
+    #[test]
+    fn should_succeed() {
+        assert_that!(&Some(2), is_some());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail() {
+        assert_that!(&None::<i32>, is_some());
+    }
+
+    #[test]
+    fn should_succeed_if_negated() {
+        assert_that!(&None::<i32>, not(is_some()));
+    }
+}
+
+mod is_none {
+    use galvanic_assert::matchers::not;
+    use galvanic_assert::matchers::variant::is_none;
+
+    #[test]
+    fn should_succeed() {
+        assert_that!(&None::<i32>, is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail() {
+        assert_that!(&Some(2), is_none());
+    }
+
+    #[test]
+    fn should_succeed_if_negated() {
+        assert_that!(&Some(2), not(is_none()));
+    }
+}
+
 mod maybe_some {
     use galvanic_assert::matchers::equal_to;
     use galvanic_assert::matchers::variant::maybe_some;
@@ -65,6 +157,69 @@ mod maybe_some {
     }
 }
 
+mod some_value {
+    use galvanic_assert::matchers::{equal_to, greater_than};
+    use galvanic_assert::matchers::variant::some_value;
+
+    #[test]
+    fn should_succeed() {
+        assert_that!(&Some(4), some_value(greater_than(3)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail_because_of_none_value() {
+        assert_that!(&None, some_value(equal_to(2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail_because_nested_matcher_fails() {
+        assert_that!(&Some(3), some_value(equal_to(2)));
+    }
+}
+
+mod is_ok {
+    use galvanic_assert::matchers::*;
+    use galvanic_assert::matchers::variant::is_ok;
+
+    #[test]
+    fn should_succeed() {
+        let maybe_int: Result<i32, String> = Ok(2);
+        assert_that!(&maybe_int, is_ok());
+    }
+
+    #[test]
+    fn should_succeed_combined_with_other_matchers() {
+        let maybe_int: Result<i32, String> = Ok(2);
+        assert_that!(&maybe_int, all_of!(is_ok(), equal_to(Ok(2))));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail() {
+        let maybe_int: Result<i32, String> = Err("Failed".to_owned());
+        assert_that!(&maybe_int, is_ok());
+    }
+}
+
+mod is_err {
+    use galvanic_assert::matchers::variant::is_err;
+
+    #[test]
+    fn should_succeed() {
+        let maybe_int: Result<i32, String> = Err("Failed".to_owned());
+        assert_that!(&maybe_int, is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail() {
+        let maybe_int: Result<i32, String> = Ok(2);
+        assert_that!(&maybe_int, is_err());
+    }
+}
+
 mod maybe_ok {
     use galvanic_assert::matchers::equal_to;
     use galvanic_assert::matchers::variant::maybe_ok;
@@ -90,6 +245,30 @@ mod maybe_ok {
     }
 }
 
+mod ok_value {
+    use galvanic_assert::matchers::equal_to;
+    use galvanic_assert::matchers::variant::ok_value;
+
+    #[test]
+    fn should_succeed() {
+        assert_that!(&"5".parse::<i32>(), ok_value(equal_to(5)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail_because_of_err_value() {
+        let maybe_int: Result<i32, String> = Err("Failed".to_owned());
+        assert_that!(&maybe_int, ok_value(equal_to(2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail_because_nested_matcher_fails() {
+        let maybe_int: Result<i32, String> = Ok(3);
+        assert_that!(&maybe_int, ok_value(equal_to(2)));
+    }
+}
+
 mod maybe_err {
     use galvanic_assert::matchers::equal_to;
     use galvanic_assert::matchers::variant::maybe_err;
@@ -114,3 +293,139 @@ mod maybe_err {
         assert_that!(&maybe_int, maybe_err(equal_to(2)));
     }
 }
+
+mod err_value {
+    use galvanic_assert::matchers::equal_to;
+    use galvanic_assert::matchers::variant::err_value;
+
+    #[test]
+    fn should_succeed() {
+        let maybe_int: Result<String, i32> = Err(2);
+        assert_that!(&maybe_int, err_value(equal_to(2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail_because_of_ok_value() {
+        let maybe_int: Result<String, i32> = Ok("Ok".to_owned());
+        assert_that!(&maybe_int, err_value(equal_to(2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_fail_because_nested_matcher_fails() {
+        let maybe_int: Result<String, i32> = Err(3);
+        assert_that!(&maybe_int, err_value(equal_to(2)));
+    }
+}
+
+mod err_matching {
+    use galvanic_assert::matchers::equal_to;
+    use galvanic_assert::matchers::variant::err_matching;
+    use std::io::ErrorKind;
+
+    #[test]
+    fn should_succeed() {
+        let maybe_int: Result<String, i32> = Err(2);
+        assert_that!(&maybe_int, err_matching(equal_to(2)));
+    }
+
+    #[test]
+    fn should_succeed_with_variant_of_error_kind() {
+        let result: Result<(), ErrorKind> = Err(ErrorKind::NotFound);
+        assert_that!(&result, err_matching(variant!(ErrorKind::NotFound)));
+    }
+
+    #[test]
+    fn should_fail_because_of_ok_value() {
+        let maybe_int: Result<String, i32> = Ok("Ok".to_owned());
+        assert_that!(
+            assert_that!(&maybe_int, err_matching(equal_to(2))),
+            panics with message "passed Result is Ok(\"Ok\"); cannot evaluate nested matcher"
+        );
+    }
+
+    #[test]
+    fn should_fail_because_nested_matcher_fails() {
+        let result: Result<(), ErrorKind> = Err(ErrorKind::PermissionDenied);
+        assert_that!(
+            assert_that!(&result, err_matching(variant!(ErrorKind::NotFound))),
+            panics
+        );
+    }
+}
+
+mod has_source_matching {
+    use galvanic_assert::check_that;
+    use galvanic_assert::matchers::satisfies;
+    use galvanic_assert::matchers::variant::has_source_matching;
+    use std::error::Error;
+    use std::fmt;
+    use std::io;
+
+    #[derive(Debug)]
+    struct ConfigError(io::Error);
+
+    impl fmt::Display for ConfigError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "cannot load config") }
+    }
+
+    impl Error for ConfigError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> { Some(&self.0) }
+    }
+
+    fn config_error(kind: io::ErrorKind) -> Box<dyn Error> {
+        Box::new(ConfigError(io::Error::new(kind, "config.toml")))
+    }
+
+    #[test]
+    fn should_succeed_for_source() {
+        let error = config_error(io::ErrorKind::NotFound);
+        assert_that!(&error, has_source_matching(satisfies("is NotFound", |e: &io::Error| e.kind() == io::ErrorKind::NotFound)));
+    }
+
+    #[test]
+    fn should_succeed_for_asserted_error_itself() {
+        let error = config_error(io::ErrorKind::NotFound);
+        assert_that!(&error, has_source_matching(satisfies("any", |_: &ConfigError| true)));
+    }
+
+    #[test]
+    fn should_fail_with_displayed_chain() {
+        let error = config_error(io::ErrorKind::PermissionDenied);
+        let result = check_that(&error, has_source_matching(satisfies("is NotFound", |e: &io::Error| e.kind() == io::ErrorKind::NotFound)));
+        let reason = result.reason().unwrap();
+        assert_that!(reason.starts_with("  Because: no source of type"));
+        assert_that!(reason.ends_with("in the error chain satisfies the matcher:\n\t0: cannot load config\n\t1: config.toml"));
+    }
+}
+
+mod matches_pattern {
+    #[test]
+    fn should_match_pattern() {
+        assert_that!(&Some(3), matches_pattern!(Some(_)));
+        assert_that!(&'c', matches_pattern!('a'..='z'));
+        assert_that!(&(1, "one".to_owned()), matches_pattern!((1, ref s) if s == "one"));
+    }
+
+    #[test]
+    fn should_match_pattern_with_guard() {
+        assert_that!(&Some(3), matches_pattern!(Some(n) if n > 0));
+    }
+
+    #[test]
+    fn should_fail_if_guard_does_not_hold() {
+        assert_that!(
+            assert_that!(&Some(-3), matches_pattern!(Some(n) if n > 0)),
+            panics with message "Some(-3) does not match the pattern 'Some(n) if n > 0'"
+        );
+    }
+
+    #[test]
+    fn should_fail_if_pattern_does_not_match() {
+        assert_that!(
+            assert_that!(&None::<i32>, matches_pattern!(Some(_))),
+            panics with message "None does not match the pattern 'Some(_)'"
+        );
+    }
+}